@@ -0,0 +1,304 @@
+//! Curve analysis, finding where a function turns, which way it
+//! bends, and where that changes, for any type that can be
+//! differentiated and evaluated, through
+//! [CurveAnalysis](trait.CurveAnalysis.html).
+//!
+//! Roots of the derivatives are found numerically on a given domain,
+//! by sampling it and refining each sign change, so points closer
+//! together than the spacing of the samples may be missed.
+
+use num_traits::{Float, Pow};
+
+use crate::{
+    traits::{Differentiation, TermTrait},
+    util::cast,
+};
+
+/// The number of pieces the domain is sampled in when looking for
+/// roots of the derivatives
+const SAMPLES: usize = 512;
+
+/// The kind of a critical point, where the derivative is 0
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CriticalPoint {
+    /// A local minimum
+    Minimum,
+    /// A local maximum
+    Maximum,
+    /// Neither, such as at 0 for x^3, where the function flattens out
+    /// without turning
+    Saddle,
+}
+
+/// Which way a function goes over an interval
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Monotonicity {
+    Increasing,
+    Decreasing,
+    Constant,
+}
+
+/// Which way a function bends over an interval
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Concavity {
+    /// The second derivative is positive, as for x^2
+    Up,
+    /// The second derivative is negative, as for -x^2
+    Down,
+    /// The second derivative is 0, as for a line
+    Straight,
+}
+
+/// Returns -1, 0 or 1, matching the sign of `x`
+fn sign<T: Float>(x: T) -> i8 {
+    if x > T::zero() {
+        1
+    } else if x < T::zero() {
+        -1
+    } else {
+        0
+    }
+}
+
+/// Finds a root of `g` between `a` and `b`, where it changes sign
+fn bisect<T: Float, G: Fn(T) -> T>(g: &G, mut a: T, mut b: T) -> T {
+    let two = cast::<T, _>(2.);
+    let mut ga = g(a);
+    for _ in 0..128 {
+        let middle = (a + b) / two;
+        if middle <= a || middle >= b {
+            break;
+        }
+        let gm = g(middle);
+        if gm == T::zero() {
+            return middle;
+        }
+        if sign(gm) == sign(ga) {
+            a = middle;
+            ga = gm;
+        } else {
+            b = middle;
+        }
+    }
+    (a + b) / two
+}
+
+/// Finds where `|g|` is smallest on `[a, b]` with a golden section
+/// search
+fn smallest<T: Float, G: Fn(T) -> T>(g: &G, mut a: T, mut b: T) -> T {
+    let ratio = cast::<T, _>((5f64.sqrt() - 1.) / 2.);
+    for _ in 0..128 {
+        if b - a <= T::epsilon() * (a.abs() + b.abs()) {
+            break;
+        }
+        let x1 = b - ratio * (b - a);
+        let x2 = a + ratio * (b - a);
+        if g(x1).abs() < g(x2).abs() {
+            b = x2;
+        } else {
+            a = x1;
+        }
+    }
+    (a + b) / cast(2.)
+}
+
+/// Returns the roots of `g` in `[lower, upper]`, in ascending order.
+/// Sign changes between samples are bisected, and samples smaller
+/// than both neighbours are refined in case `g` touches 0 there
+/// without crossing it. If `g` is 0 everywhere, there are none
+fn roots<T: Float, G: Fn(T) -> T>(g: &G, lower: T, upper: T) -> Vec<T> {
+    let step = (upper - lower) / cast(SAMPLES as f64);
+    let xs: Vec<T> = (0..=SAMPLES)
+        .map(|i| {
+            if i == SAMPLES {
+                upper
+            } else {
+                lower + step * cast(i as f64)
+            }
+        })
+        .collect();
+    let values: Vec<T> = xs.iter().map(|x| g(*x)).collect();
+    let scale = values.iter().fold(T::zero(), |max, v| max.max(v.abs()));
+    if scale == T::zero() {
+        return Vec::new();
+    }
+    let tolerance = scale * T::epsilon().sqrt() * cast(1e-3);
+
+    let mut found = Vec::new();
+    for i in 0..=SAMPLES {
+        let (x, value) = (xs[i], values[i]);
+        if value == T::zero() {
+            found.push(x);
+        } else if i > 0 && sign(values[i - 1]) == -sign(value) {
+            found.push(bisect(g, xs[i - 1], x));
+        } else if i > 0
+            && i < SAMPLES
+            && sign(values[i - 1]) == sign(value)
+            && sign(values[i + 1]) == sign(value)
+            && value.abs() <= values[i - 1].abs()
+            && value.abs() <= values[i + 1].abs()
+        {
+            let candidate = smallest(g, xs[i - 1], xs[i + 1]);
+            if g(candidate).abs() <= tolerance {
+                found.push(candidate);
+            }
+        }
+    }
+    found.sort_by(|a, b| a.partial_cmp(b).unwrap_or(core::cmp::Ordering::Equal));
+    // A root at a sample can also be found from its neighbours
+    let half_step = step / cast(2.);
+    found.dedup_by(|later, earlier| *later - *earlier <= half_step);
+    found
+}
+
+/// Returns the sign of `g` just left and just right of `x`, staying
+/// within `[lower, upper]`
+fn signs_around<T: Float, G: Fn(T) -> T>(g: &G, x: T, lower: T, upper: T) -> (i8, i8) {
+    let offset = (upper - lower) / cast((SAMPLES * 4) as f64);
+    (
+        sign(g((x - offset).max(lower))),
+        sign(g((x + offset).min(upper))),
+    )
+}
+
+/// Splits `[lower, upper]` at `points`, labelling each part by the
+/// sign of `g` at its middle, and joining neighbours with the same
+/// label
+fn intervals<T, G, L, K>(g: &G, lower: T, upper: T, points: &[T], label: L) -> Vec<(T, T, K)>
+where
+    T: Float,
+    G: Fn(T) -> T,
+    L: Fn(i8) -> K,
+    K: PartialEq,
+{
+    let mut ends = vec![lower];
+    ends.extend(points.iter().copied().filter(|x| *x > lower && *x < upper));
+    ends.push(upper);
+
+    let mut parts: Vec<(T, T, K)> = Vec::new();
+    for pair in ends.windows(2) {
+        let kind = label(sign(g((pair[0] + pair[1]) / cast(2.))));
+        match parts.last_mut() {
+            Some(last) if last.2 == kind => last.1 = pair[1],
+            _ => parts.push((pair[0], pair[1], kind)),
+        }
+    }
+    parts
+}
+
+/// Analysis of a function's shape on a domain `[lower, upper]`,
+/// automatically implemented for anything that can be differentiated
+/// and evaluated, including every [Calculus](../traits/trait.Calculus.html) type
+/// ```
+/// use cakcukus::{analysis::{CriticalPoint, CurveAnalysis}, terms, Polynomial};
+///
+/// // x^3 - 3x turns at -1 and 1
+/// let p: Polynomial<f64> = terms!(1., 3., -3., 1.);
+/// let extrema = p.classify_extrema(-3., 3.);
+///
+/// assert_eq!(extrema.len(), 2);
+/// assert!((extrema[0].0 + 1.).abs() < 1e-12);
+/// assert_eq!(extrema[0].1, CriticalPoint::Maximum);
+/// assert_eq!(extrema[1].1, CriticalPoint::Minimum);
+/// ```
+pub trait CurveAnalysis<T>: Differentiation<T> + TermTrait<T> + Sized
+where
+    T: Float + Pow<T, Output = T>,
+{
+    /// Returns the points in `[lower, upper]` where the derivative is
+    /// 0, in ascending order
+    ///
+    /// # Panics
+    /// If `lower` isn't less than `upper`
+    fn critical_points(&self, lower: T, upper: T) -> Vec<T> {
+        assert!(lower < upper, "The domain must have lower < upper");
+        roots(&|x| self.differentiate(&x), lower, upper)
+    }
+    /// Returns each critical point in `[lower, upper]` with its kind,
+    /// decided by the sign of the derivative either side of it, or
+    /// where that's inconclusive, by the sign of the second derivative
+    ///
+    /// # Panics
+    /// If `lower` isn't less than `upper`
+    fn classify_extrema(&self, lower: T, upper: T) -> Vec<(T, CriticalPoint)> {
+        let derivative = |x: T| self.differentiate(&x);
+        let second = self.differentiate_self();
+        self.critical_points(lower, upper)
+            .into_iter()
+            .map(|x| {
+                let kind = match signs_around(&derivative, x, lower, upper) {
+                    (-1, 1) => CriticalPoint::Minimum,
+                    (1, -1) => CriticalPoint::Maximum,
+                    (left, right) if left == right && left != 0 => CriticalPoint::Saddle,
+                    _ => match sign(second.differentiate(&x)) {
+                        1 => CriticalPoint::Minimum,
+                        -1 => CriticalPoint::Maximum,
+                        _ => CriticalPoint::Saddle,
+                    },
+                };
+                (x, kind)
+            })
+            .collect()
+    }
+    /// Returns the points in `[lower, upper]` where the concavity
+    /// changes, so where the second derivative crosses 0, rather than
+    /// only touching it as for x^4
+    ///
+    /// # Panics
+    /// If `lower` isn't less than `upper`
+    fn inflection_points(&self, lower: T, upper: T) -> Vec<T> {
+        assert!(lower < upper, "The domain must have lower < upper");
+        let derivative = self.differentiate_self();
+        let second = |x: T| derivative.differentiate(&x);
+        roots(&second, lower, upper)
+            .into_iter()
+            .filter(|x| {
+                let (left, right) = signs_around(&second, *x, lower, upper);
+                left != 0 && left == -right
+            })
+            .collect()
+    }
+    /// Splits `[lower, upper]` into the intervals the function is
+    /// increasing, decreasing or constant on, as `(start, end, kind)`
+    ///
+    /// # Panics
+    /// If `lower` isn't less than `upper`
+    fn monotonicity(&self, lower: T, upper: T) -> Vec<(T, T, Monotonicity)> {
+        let critical = self.critical_points(lower, upper);
+        intervals(
+            &|x| self.differentiate(&x),
+            lower,
+            upper,
+            &critical,
+            |sign| match sign {
+                1 => Monotonicity::Increasing,
+                -1 => Monotonicity::Decreasing,
+                _ => Monotonicity::Constant,
+            },
+        )
+    }
+    /// Splits `[lower, upper]` into the intervals the function is
+    /// concave up, concave down or straight on, as
+    /// `(start, end, kind)`
+    ///
+    /// # Panics
+    /// If `lower` isn't less than `upper`
+    fn concavity(&self, lower: T, upper: T) -> Vec<(T, T, Concavity)> {
+        assert!(lower < upper, "The domain must have lower < upper");
+        let derivative = self.differentiate_self();
+        let second = |x: T| derivative.differentiate(&x);
+        let points = roots(&second, lower, upper);
+        intervals(&second, lower, upper, &points, |sign| match sign {
+            1 => Concavity::Up,
+            -1 => Concavity::Down,
+            _ => Concavity::Straight,
+        })
+    }
+}
+
+impl<C, T> CurveAnalysis<T> for C
+where
+    C: Differentiation<T> + TermTrait<T>,
+    T: Float + Pow<T, Output = T>,
+{
+}
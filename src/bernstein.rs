@@ -0,0 +1,312 @@
+//! Polynomials in the Bernstein basis on [0, 1], and the Bézier curves
+//! built from them. Their coefficients act as control points, and
+//! de Casteljau's algorithm evaluates and splits them stably.
+
+use num_traits::{Float, Num, Pow};
+
+use crate::{
+    quadrature::gauss_legendre,
+    traits::{Differentiation, Integration, TermTrait},
+    util::{cast, from_u32},
+    Polynomial,
+};
+
+/// Returns n choose k
+fn binomial<T: Num + Clone>(n: usize, k: usize) -> T {
+    let mut result = T::one();
+    for i in 0..k.min(n - k) {
+        result = result * from_u32(n as u32 - i as u32) / from_u32(i as u32 + 1);
+    }
+    result
+}
+
+/// Runs de Casteljau's algorithm on `points`, with `lerp` moving
+/// between neighbouring points, returning the control points of the
+/// parts before and after the split
+fn de_casteljau<P, L>(points: &[P], lerp: L) -> (Vec<P>, Vec<P>)
+where
+    P: Clone,
+    L: Fn(&P, &P) -> P,
+{
+    let mut before = Vec::with_capacity(points.len());
+    let mut after = Vec::with_capacity(points.len());
+    let mut row = points.to_vec();
+    while let Some(first) = row.first() {
+        before.push(first.clone());
+        after.push(row[row.len() - 1].clone());
+        row = row
+            .windows(2)
+            .map(|pair| lerp(&pair[0], &pair[1]))
+            .collect();
+    }
+    after.reverse();
+    (before, after)
+}
+
+/// A polynomial `b0 B(0, n) + b1 B(1, n) + ... + bn B(n, n)` on
+/// [0, 1], where `B(k, n) = C(n, k) t^k (1 - t)^(n - k)`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Bernstein<T>
+where
+    T: Num + Pow<T, Output = T> + Copy + PartialOrd,
+{
+    coefficients: Vec<T>,
+}
+
+impl<T> Bernstein<T>
+where
+    T: Num + Pow<T, Output = T> + Copy + PartialOrd,
+{
+    /// Constructs a new polynomial from its Bernstein coefficients
+    pub fn new(coefficients: Vec<T>) -> Self {
+        Self { coefficients }
+    }
+    pub fn coefficients(&self) -> &[T] {
+        &self.coefficients
+    }
+    pub fn degree(&self) -> usize {
+        self.coefficients.len().saturating_sub(1)
+    }
+    /// Converts a polynomial into the Bernstein basis of its degree,
+    /// using `t^j = sum of C(k, j) / C(n, j) B(k, n)` for k >= j
+    ///
+    /// # Panics
+    /// If any exponent isn't a whole number of at least 0
+    /// ```
+    /// use cakcukus::{bernstein::Bernstein, terms, Polynomial};
+    ///
+    /// // 1 - 2t + 3t^2
+    /// let p: Polynomial<f64> = terms!(3., 2., -2., 1., 1., 0.);
+    ///
+    /// assert_eq!(Bernstein::from_polynomial(&p).coefficients(), &[1., 0., 2.]);
+    /// ```
+    pub fn from_polynomial(polynomial: &Polynomial<T>) -> Self {
+        let monomial = polynomial
+            .dense_coefficients()
+            .expect("Only whole, non-negative exponents have a Bernstein form");
+        let n = monomial.len().saturating_sub(1);
+        let coefficients = (0..monomial.len())
+            .map(|k| {
+                monomial
+                    .iter()
+                    .enumerate()
+                    .take(k + 1)
+                    .fold(T::zero(), |total, (j, a)| {
+                        total + *a * binomial::<T>(k, j) / binomial(n, j)
+                    })
+            })
+            .collect();
+        Self::new(coefficients)
+    }
+    /// Converts back into the monomial basis
+    pub fn to_polynomial(&self) -> Polynomial<T> {
+        let n = self.degree();
+        let monomial: Vec<T> = (0..self.coefficients.len())
+            .map(|j| {
+                let sum = self.coefficients.iter().enumerate().take(j + 1).fold(
+                    T::zero(),
+                    |total, (k, b)| {
+                        let term = binomial::<T>(j, k) * *b;
+                        if (j - k) % 2 == 0 {
+                            total + term
+                        } else {
+                            total - term
+                        }
+                    },
+                );
+                sum * binomial(n, j)
+            })
+            .collect();
+        Polynomial::from_coefficients(&monomial)
+    }
+    /// Splits the polynomial at `t`, returning the polynomials on
+    /// [0, t] and [t, 1], each reparametrised to [0, 1]
+    pub fn subdivide(&self, t: T) -> (Self, Self) {
+        let (before, after) = de_casteljau(&self.coefficients, |a, b| *a + (*b - *a) * t);
+        (Self::new(before), Self::new(after))
+    }
+    /// Returns the same polynomial in the basis of one degree higher
+    pub fn elevate(&self) -> Self {
+        let n: T = from_u32(self.coefficients.len() as u32);
+        let mut elevated = Vec::with_capacity(self.coefficients.len() + 1);
+        for k in 0..=self.coefficients.len() {
+            let ratio = from_u32::<T>(k as u32) / n;
+            let previous = if k == 0 {
+                T::zero()
+            } else {
+                self.coefficients[k - 1]
+            };
+            let current = self.coefficients.get(k).copied().unwrap_or_else(T::zero);
+            elevated.push(ratio * previous + (T::one() - ratio) * current);
+        }
+        Self::new(elevated)
+    }
+}
+
+impl<T> TermTrait<T> for Bernstein<T>
+where
+    T: Num + Pow<T, Output = T> + Copy + PartialOrd,
+{
+    /// Evaluates the polynomial with de Casteljau's algorithm
+    fn sum_with_respect_to(&self, x: &T) -> T {
+        let mut row = self.coefficients.clone();
+        while row.len() > 1 {
+            row = row
+                .windows(2)
+                .map(|pair| pair[0] + (pair[1] - pair[0]) * *x)
+                .collect();
+        }
+        row.first().copied().unwrap_or_else(T::zero)
+    }
+}
+
+impl<T> Differentiation<T> for Bernstein<T>
+where
+    T: Num + Pow<T, Output = T> + Copy + PartialOrd,
+{
+    fn differentiate(&self, x: &T) -> T {
+        self.differentiate_self().sum_with_respect_to(x)
+    }
+    /// The derivative has the coefficients `n(b(k + 1) - b(k))`, one
+    /// degree lower
+    fn differentiate_self(&self) -> Self {
+        let n: T = from_u32(self.degree() as u32);
+        Self::new(
+            self.coefficients
+                .windows(2)
+                .map(|pair| n * (pair[1] - pair[0]))
+                .collect(),
+        )
+    }
+}
+
+impl<T> Integration<T> for Bernstein<T>
+where
+    T: Num + Pow<T, Output = T> + Copy + PartialOrd,
+{
+    /// Intergrates a copy of self, with a constant of 0, giving the
+    /// coefficients `(b0 + ... + b(k - 1)) / (n + 1)`
+    fn integrate_self(&self) -> Self {
+        let n: T = from_u32(self.coefficients.len() as u32);
+        let mut integrated = vec![T::zero()];
+        let mut total = T::zero();
+        for b in self.coefficients.iter() {
+            total = total + *b / n;
+            integrated.push(total);
+        }
+        Self::new(integrated)
+    }
+    fn integrate(&self, lower: T, upper: T) -> T {
+        let integrated = self.integrate_self();
+        integrated.sum_with_respect_to(&upper) - integrated.sum_with_respect_to(&lower)
+    }
+}
+
+/// Returns the Euclidean length of a vector
+fn length<T: Float, const D: usize>(vector: [T; D]) -> T {
+    vector.iter().fold(T::zero(), |total, x| total.hypot(*x))
+}
+
+/// A Bézier curve in `D` dimensions, traced out as t goes from 0 to 1
+/// ```
+/// use cakcukus::bernstein::Bezier;
+///
+/// // A quarter of a (nearly) circular arc
+/// let curve = Bezier::new(vec![[1f64, 0.], [1., 0.552], [0.552, 1.], [0., 1.]]);
+///
+/// let middle = curve.point(0.5);
+/// assert!(((middle[0] * middle[0] + middle[1] * middle[1]).sqrt() - 1.).abs() < 1e-3);
+/// assert!((curve.arc_length() - std::f64::consts::FRAC_PI_2).abs() < 1e-3);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Bezier<T, const D: usize>
+where
+    T: Float + Pow<T, Output = T>,
+{
+    control_points: Vec<[T; D]>,
+}
+
+impl<T, const D: usize> Bezier<T, D>
+where
+    T: Float + Pow<T, Output = T>,
+{
+    /// Constructs a new curve from its control points
+    pub fn new(control_points: Vec<[T; D]>) -> Self {
+        Self { control_points }
+    }
+    pub fn control_points(&self) -> &[[T; D]] {
+        &self.control_points
+    }
+    pub fn degree(&self) -> usize {
+        self.control_points.len().saturating_sub(1)
+    }
+    /// Returns the Bernstein polynomial tracing out one coordinate
+    pub fn coordinate(&self, axis: usize) -> Bernstein<T> {
+        Bernstein::new(self.control_points.iter().map(|p| p[axis]).collect())
+    }
+    /// Returns the point on the curve at `t`
+    pub fn point(&self, t: T) -> [T; D] {
+        let mut point = [T::zero(); D];
+        for (axis, value) in point.iter_mut().enumerate() {
+            *value = self.coordinate(axis).sum_with_respect_to(&t);
+        }
+        point
+    }
+    /// Splits the curve at `t`, returning the two halves, each
+    /// traced out as their own t goes from 0 to 1
+    pub fn subdivide(&self, t: T) -> (Self, Self) {
+        let (before, after) = de_casteljau(&self.control_points, |a, b| {
+            let mut point = *a;
+            for (value, end) in point.iter_mut().zip(b.iter()) {
+                *value = *value + (*end - *value) * t;
+            }
+            point
+        });
+        (Self::new(before), Self::new(after))
+    }
+    /// Returns the same curve with one more control point
+    pub fn elevate(&self) -> Self {
+        let axes: Vec<Bernstein<T>> = (0..D).map(|axis| self.coordinate(axis).elevate()).collect();
+        self.rebuild(&axes)
+    }
+    /// Returns the curve's derivative, or hodograph, whose control
+    /// points are `n(P(k + 1) - P(k))`
+    pub fn derivative(&self) -> Self {
+        let axes: Vec<Bernstein<T>> = (0..D)
+            .map(|axis| self.coordinate(axis).differentiate_self())
+            .collect();
+        self.rebuild(&axes)
+    }
+    /// Builds a curve from a Bernstein polynomial for each coordinate
+    fn rebuild(&self, axes: &[Bernstein<T>]) -> Self {
+        let count = axes.first().map_or(0, |axis| axis.coefficients.len());
+        let mut control_points = vec![[T::zero(); D]; count];
+        for (axis, bernstein) in axes.iter().enumerate() {
+            for (point, value) in control_points.iter_mut().zip(bernstein.coefficients.iter()) {
+                point[axis] = *value;
+            }
+        }
+        Self::new(control_points)
+    }
+    /// Returns the speed `|B'(t)|` of the curve at `t`
+    pub fn speed(&self, t: T) -> T {
+        length(self.derivative().point(t))
+    }
+    /// Returns the length of the curve between `lower` and `upper`,
+    /// by intergrating its speed with the Gauss–Legendre rule over
+    /// a few pieces
+    pub fn arc_length_between(&self, lower: T, upper: T) -> T {
+        let hodograph = self.derivative();
+        let speed = |t: T| length(hodograph.point(t));
+        let pieces = 8;
+        let width = (upper - lower) / cast::<T, _>(pieces);
+        (0..pieces).fold(T::zero(), |total, i| {
+            let start = lower + width * cast::<T, _>(i);
+            total + gauss_legendre(speed, start, start + width, 16)
+        })
+    }
+    /// Returns the length of the whole curve
+    pub fn arc_length(&self) -> T {
+        self.arc_length_between(T::zero(), T::one())
+    }
+}
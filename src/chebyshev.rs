@@ -0,0 +1,268 @@
+//! Near minimax approximation of functions on an interval by sums of
+//! Chebyshev polynomials, which can be differentiated and
+//! intergrated without ever leaving the Chebyshev basis.
+
+use num_traits::{Float, Pow};
+
+use crate::{
+    traits::{Differentiation, Integration, TermTrait},
+    util::cast,
+    Polynomial,
+};
+
+/// The largest degree tried when approximating to a tolerance
+const MAX_DEGREE: usize = 1024;
+
+/// How closely an approximation should follow its function
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Accuracy<T> {
+    /// Use exactly this degree
+    Degree(usize),
+    /// Use the smallest degree whose dropped coefficients are all
+    /// below this size
+    Tolerance(T),
+}
+
+/// A function approximated on `[lower, upper]` by the sum
+/// `c0 T0(t) + c1 T1(t) + ...`, where `t` maps the interval onto
+/// [-1, 1]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Chebyshev<T>
+where
+    T: Float + Pow<T, Output = T>,
+{
+    coefficients: Vec<T>,
+    lower: T,
+    upper: T,
+}
+
+/// Approximates `f` on `interval` by interpolating it at the
+/// Chebyshev nodes, which is within a small factor of the best
+/// possible approximation of that degree. With a tolerance, the
+/// degree is doubled until the coefficients fall below it, up to a
+/// degree of 1024
+/// ```
+/// use cakcukus::{chebyshev::{chebyshev_approximation, Accuracy}, traits::TermTrait};
+///
+/// let exp = chebyshev_approximation(|x: f64| x.exp(), [0., 2.], Accuracy::Tolerance(1e-14));
+///
+/// assert!((exp.sum_with_respect_to(&1.5) - 1.5f64.exp()).abs() < 1e-13);
+/// ```
+pub fn chebyshev_approximation<T, F>(f: F, interval: [T; 2], accuracy: Accuracy<T>) -> Chebyshev<T>
+where
+    T: Float + Pow<T, Output = T>,
+    F: Fn(T) -> T,
+{
+    let [lower, upper] = interval;
+    match accuracy {
+        Accuracy::Degree(degree) => Chebyshev::new(&f, lower, upper, degree),
+        Accuracy::Tolerance(tolerance) => {
+            let mut degree = 8;
+            loop {
+                let mut approximation = Chebyshev::new(&f, lower, upper, degree);
+                if approximation.chop(tolerance) || degree >= MAX_DEGREE {
+                    return approximation;
+                }
+                degree *= 2;
+            }
+        }
+    }
+}
+
+impl<T> Chebyshev<T>
+where
+    T: Float + Pow<T, Output = T>,
+{
+    /// Interpolates `f` at the `degree + 1` Chebyshev nodes in
+    /// `[lower, upper]`
+    pub fn new<F: Fn(T) -> T>(f: F, lower: T, upper: T, degree: usize) -> Self {
+        let n = degree + 1;
+        let pi = cast::<T, _>(core::f64::consts::PI);
+        let half = cast::<T, _>(0.5);
+        let centre = (upper + lower) * half;
+        let half_width = (upper - lower) * half;
+
+        let angles: Vec<T> = (0..n)
+            .map(|k| pi * (cast::<T, _>(k) + half) / cast(n))
+            .collect();
+        let values: Vec<T> = angles
+            .iter()
+            .map(|angle| f(centre + half_width * angle.cos()))
+            .collect();
+        let coefficients = (0..n)
+            .map(|j| {
+                let sum = angles
+                    .iter()
+                    .zip(values.iter())
+                    .fold(T::zero(), |total, (angle, value)| {
+                        total + *value * (cast::<T, _>(j) * *angle).cos()
+                    });
+                let scale = if j == 0 { T::one() } else { cast(2) };
+                sum * scale / cast(n)
+            })
+            .collect();
+        Self::from_coefficients(coefficients, lower, upper)
+    }
+    /// Constructs an approximation from its Chebyshev coefficients on
+    /// `[lower, upper]`
+    pub fn from_coefficients(coefficients: Vec<T>, lower: T, upper: T) -> Self {
+        Self {
+            coefficients,
+            lower,
+            upper,
+        }
+    }
+    /// The coefficients `c0, c1, ...` of `T0(t), T1(t), ...`
+    pub fn coefficients(&self) -> &[T] {
+        &self.coefficients
+    }
+    /// The interval the approximation is valid on
+    pub fn interval(&self) -> [T; 2] {
+        [self.lower, self.upper]
+    }
+    pub fn degree(&self) -> usize {
+        self.coefficients.len().saturating_sub(1)
+    }
+    /// Estimates the largest error of the approximation from the size
+    /// of its last two coefficients, as the coefficients of smooth
+    /// functions fall off geometrically
+    pub fn error_estimate(&self) -> T {
+        self.coefficients
+            .iter()
+            .rev()
+            .take(2)
+            .fold(T::zero(), |max, c| max.max(c.abs()))
+    }
+    /// Drops the trailing coefficients smaller than `tolerance`,
+    /// returning whether any were dropped, as that means the
+    /// approximation had resolved the function
+    fn chop(&mut self, tolerance: T) -> bool {
+        let scale = self
+            .coefficients
+            .iter()
+            .fold(T::zero(), |max, c| max.max(c.abs()));
+        let threshold = tolerance.max(scale * T::epsilon());
+        let kept = self
+            .coefficients
+            .iter()
+            .rposition(|c| c.abs() > threshold)
+            .map_or(1, |last| last + 1);
+        // Requiring a few negligible coefficients guards against one
+        // that happens to be small
+        if self.coefficients.len() - kept < 3 {
+            return false;
+        }
+        self.coefficients.truncate(kept);
+        true
+    }
+    /// Maps x from the interval onto [-1, 1]
+    fn to_unit(&self, x: T) -> T {
+        (x + x - self.lower - self.upper) / (self.upper - self.lower)
+    }
+    /// Converts the approximation into a polynomial in x. This is
+    /// badly conditioned for high degrees, where the approximation
+    /// itself should be used instead
+    pub fn to_polynomial(&self) -> Polynomial<T> {
+        let in_t = Polynomial::from_chebyshev(&self.coefficients)
+            .dense_coefficients()
+            .unwrap_or_default();
+        // Substitutes t = scale x + shift by Horner's method
+        let scale = cast::<T, _>(2) / (self.upper - self.lower);
+        let shift = -(self.lower + self.upper) / (self.upper - self.lower);
+        let mut expanded: Vec<T> = Vec::with_capacity(in_t.len());
+        for coefficient in in_t.iter().rev() {
+            let mut next = vec![T::zero(); expanded.len() + 1];
+            for (power, value) in expanded.iter().enumerate() {
+                next[power + 1] = next[power + 1] + scale * *value;
+                next[power] = next[power] + shift * *value;
+            }
+            next[0] = next[0] + *coefficient;
+            expanded = next;
+        }
+        Polynomial::from_coefficients(&expanded)
+    }
+}
+
+impl<T> TermTrait<T> for Chebyshev<T>
+where
+    T: Float + Pow<T, Output = T>,
+{
+    /// Evaluates the sum with Clenshaw's recurrence
+    fn sum_with_respect_to(&self, x: &T) -> T {
+        let t = self.to_unit(*x);
+        let (mut b1, mut b2) = (T::zero(), T::zero());
+        for coefficient in self.coefficients.iter().skip(1).rev() {
+            let b = *coefficient + (t + t) * b1 - b2;
+            b2 = b1;
+            b1 = b;
+        }
+        let c0 = self.coefficients.first().copied().unwrap_or_else(T::zero);
+        c0 + t * b1 - b2
+    }
+}
+
+impl<T> Differentiation<T> for Chebyshev<T>
+where
+    T: Float + Pow<T, Output = T>,
+{
+    fn differentiate(&self, x: &T) -> T {
+        self.differentiate_self().sum_with_respect_to(x)
+    }
+    /// Uses `c'(k - 1) = c'(k + 1) + 2k c(k)`, which needs no
+    /// conversion to the monomial basis
+    fn differentiate_self(&self) -> Self {
+        let n = self.coefficients.len();
+        let mut derivative = vec![T::zero(); n + 1];
+        for k in (1..n).rev() {
+            derivative[k - 1] = derivative[k + 1] + cast::<T, _>(2 * k) * self.coefficients[k];
+        }
+        derivative.truncate(n.saturating_sub(1).max(1));
+        derivative[0] = derivative[0] / cast(2);
+        // d/dx = dt/dx d/dt
+        let scale = cast::<T, _>(2) / (self.upper - self.lower);
+        Self::from_coefficients(
+            derivative.into_iter().map(|c| c * scale).collect(),
+            self.lower,
+            self.upper,
+        )
+    }
+}
+
+impl<T> Integration<T> for Chebyshev<T>
+where
+    T: Float + Pow<T, Output = T>,
+{
+    /// Intergrates a copy of self, choosing the constant so that the
+    /// result is 0 at the start of the interval
+    fn integrate_self(&self) -> Self {
+        let c = |k: usize| self.coefficients.get(k).copied().unwrap_or_else(T::zero);
+        let n = self.coefficients.len();
+        let mut integral = vec![T::zero(); n + 1];
+        for (k, value) in integral.iter_mut().enumerate().skip(1) {
+            // The first coefficient counts twice, as T0 is integrated
+            // into T1 rather than (T0 + T2) / 2
+            let previous = if k == 1 { c(0) + c(0) } else { c(k - 1) };
+            *value = (previous - c(k + 1)) / cast(2 * k);
+        }
+        // Each Tk(-1) is (-1)^k
+        let mut at_lower = T::zero();
+        for (k, value) in integral.iter().enumerate().skip(1) {
+            at_lower = if k % 2 == 0 {
+                at_lower + *value
+            } else {
+                at_lower - *value
+            };
+        }
+        integral[0] = -at_lower;
+        let scale = (self.upper - self.lower) / cast(2);
+        Self::from_coefficients(
+            integral.into_iter().map(|c| c * scale).collect(),
+            self.lower,
+            self.upper,
+        )
+    }
+    fn integrate(&self, lower: T, upper: T) -> T {
+        let integrated = self.integrate_self();
+        integrated.sum_with_respect_to(&upper) - integrated.sum_with_respect_to(&lower)
+    }
+}
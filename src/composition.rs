@@ -0,0 +1,148 @@
+//! Substituting one polynomial into another, including the special
+//! cases of shifting and scaling x, all of which skip the gaps
+//! between the exponents of sparse polynomials.
+
+use num_traits::{identities::zero, Num, Pow};
+
+use crate::{util::from_u32, Polynomial, Term};
+
+/// Returns `x` as a u32, or None if it isn't a whole number between 0
+/// and `u32::MAX`
+pub(crate) fn whole<T: Num + Clone + PartialOrd>(x: &T) -> Option<u32> {
+    if *x < zero() {
+        return None;
+    }
+    let mut n = 0u32;
+    for bit in (0..32).rev() {
+        let candidate = n | 1 << bit;
+        if from_u32::<T>(candidate) <= *x {
+            n = candidate;
+        }
+    }
+    if from_u32::<T>(n) == *x {
+        Some(n)
+    } else {
+        None
+    }
+}
+
+/// Simplifies `polynomial`, dropping any terms that cancelled out
+pub(crate) fn tidy<T>(mut polynomial: Polynomial<T>) -> Polynomial<T>
+where
+    T: Num + Pow<T, Output = T> + Clone + PartialOrd,
+{
+    polynomial.simplify();
+    polynomial.0.retain(|term| !term.coefficient.is_zero());
+    polynomial
+}
+
+impl<T> Polynomial<T>
+where
+    T: Num + Pow<T, Output = T> + Clone + PartialOrd,
+{
+    /// Returns `self(inner(x))`, by Horner's method over the terms
+    /// of self, so that a gap of k between two exponents costs one
+    /// power of `inner` rather than k multiplications
+    ///
+    /// # Panics
+    /// If any exponent of self isn't a whole number of at least 0
+    /// ```
+    /// use cakcukus::{terms, Polynomial};
+    ///
+    /// // x^2 + 1, and x - 1
+    /// let outer: Polynomial<f64> = terms!(1., 2., 1., 0.);
+    /// let inner: Polynomial<f64> = terms!(1., 1., -1., 0.);
+    ///
+    /// // x^2 - 2x + 2
+    /// let expected: Polynomial<f64> = Polynomial(vec![
+    ///     terms!(1., 2.),
+    ///     terms!(-2., 1.),
+    ///     terms!(2., 0.),
+    /// ]);
+    /// assert_eq!(outer.compose(&inner), expected);
+    /// ```
+    pub fn compose(&self, inner: &Polynomial<T>) -> Self {
+        let outer = tidy(self.clone());
+        let mut terms = outer.0.iter().map(|term| {
+            let exponent =
+                whole(&term.exponent).expect("Only whole, non-negative exponents can be composed");
+            (term.coefficient.clone(), exponent)
+        });
+        let (leading, mut previous) = match terms.next() {
+            Some(first) => first,
+            None => return Self::new(),
+        };
+        let mut result = Polynomial(vec![Term::new(leading, zero())]);
+        for (coefficient, exponent) in terms {
+            result *= inner.clone().pow(previous - exponent);
+            result.0.push(Term::new(coefficient, zero()));
+            result = tidy(result);
+            previous = exponent;
+        }
+        tidy(result * inner.clone().pow(previous))
+    }
+    /// Returns `self(x + a)`, the Taylor shift of self. Each term is
+    /// expanded with the binomial theorem, so the cost depends on the
+    /// exponents present rather than every power up to the degree
+    ///
+    /// # Panics
+    /// If any exponent isn't a whole number of at least 0
+    /// ```
+    /// use cakcukus::{terms, traits::TermTrait, Polynomial};
+    ///
+    /// let p: Polynomial<f64> = terms!(1., 3., -2., 1.);
+    /// let shifted = p.shift(2.);
+    ///
+    /// assert_eq!(shifted.sum_with_respect_to(&1.), p.sum_with_respect_to(&3.));
+    /// ```
+    pub fn shift(&self, a: T) -> Self {
+        let simplified = tidy(self.clone());
+        let exponents: Vec<u32> = simplified
+            .0
+            .iter()
+            .map(|term| {
+                whole(&term.exponent).expect("Only whole, non-negative exponents can be shifted")
+            })
+            .collect();
+        let degree = exponents.first().copied().unwrap_or(0) as usize;
+        let mut coefficients = vec![zero::<T>(); degree + 1];
+        for (term, n) in simplified.0.iter().zip(exponents) {
+            // c (x + a)^n = sum of c C(n, k) a^(n - k) x^k, from k = n down
+            let mut binomial = T::one();
+            let mut a_power = T::one();
+            for k in (0..=n).rev() {
+                let index = k as usize;
+                coefficients[index] = coefficients[index].clone()
+                    + term.coefficient.clone() * binomial.clone() * a_power.clone();
+                if k > 0 {
+                    binomial = binomial * from_u32(k) / from_u32(n - k + 1);
+                    a_power = a_power * a.clone();
+                }
+            }
+        }
+        Self::from_coefficients(&coefficients)
+    }
+    /// Returns `self(kx)`, multiplying each coefficient by k raised
+    /// to its exponent
+    /// ```
+    /// use cakcukus::{terms, Polynomial};
+    ///
+    /// let p: Polynomial<f64> = terms!(1., 3., 1., 1.);
+    /// let expected: Polynomial<f64> = terms!(8., 3., 2., 1.);
+    ///
+    /// assert_eq!(p.scale(2.), expected);
+    /// ```
+    pub fn scale(&self, k: T) -> Self {
+        let scaled = self
+            .0
+            .iter()
+            .map(|term| {
+                Term::new(
+                    term.coefficient.clone() * k.clone().pow(term.exponent.clone()),
+                    term.exponent.clone(),
+                )
+            })
+            .collect();
+        tidy(Polynomial(scaled))
+    }
+}
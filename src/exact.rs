@@ -0,0 +1,377 @@
+//! Coefficient types with exact arithmetic, for algorithms such as
+//! [groebner_basis](../groebner/fn.groebner_basis.html) where
+//! floating point rounding would stop exact cancellation.
+//!
+//! Both types implement `Pow<Self>` so they can also be used with
+//! [Term](../term/struct.Term.html), though only whole exponents are
+//! supported. [Polynomial](../struct.Polynomial.html) also needs its
+//! coefficients to be ordered, which only `Rational` is.
+
+use core::{
+    cmp::Ordering,
+    convert::TryFrom,
+    fmt::{Debug, Display},
+    ops::{Add, Div, Mul, Neg, Rem, Sub},
+};
+
+use num_traits::{Num, One, Pow, Zero};
+
+fn gcd(mut a: i128, mut b: i128) -> i128 {
+    while b != 0 {
+        let t = a % b;
+        a = b;
+        b = t;
+    }
+    a.abs()
+}
+
+/// A rational number, always kept in lowest terms with a
+/// positive denominator.
+///
+/// Arithmetic panics rather than wrapping if a result's numerator
+/// or denominator doesn't fit in an i64
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rational {
+    numerator: i64,
+    denominator: i64,
+}
+
+impl Rational {
+    /// Constructs a new rational number, being
+    /// `numerator / denominator`
+    ///
+    /// # Panics
+    /// If the denominator is 0, or the result doesn't fit in i64s, as
+    /// for `i64::MIN / -1`
+    pub fn new(numerator: i64, denominator: i64) -> Self {
+        Self::reduced(numerator as i128, denominator as i128)
+    }
+    /// Constructs a new rational number with a denominator of 1
+    pub fn from_integer(integer: i64) -> Self {
+        Self {
+            numerator: integer,
+            denominator: 1,
+        }
+    }
+    pub fn numerator(&self) -> i64 {
+        self.numerator
+    }
+    pub fn denominator(&self) -> i64 {
+        self.denominator
+    }
+    /// Returns true if the denominator is 1
+    pub fn is_integer(&self) -> bool {
+        self.denominator == 1
+    }
+    /// Approximates the number as a float
+    pub fn to_f64(&self) -> f64 {
+        self.numerator as f64 / self.denominator as f64
+    }
+    /// # Panics
+    /// If the denominator is 0, or either part doesn't fit in an i64
+    /// once in lowest terms
+    fn reduced(numerator: i128, denominator: i128) -> Self {
+        assert!(denominator != 0, "Rational with a denominator of 0");
+        let divisor = gcd(numerator, denominator) * denominator.signum();
+        Self {
+            numerator: i64::try_from(numerator / divisor).expect("Rational numerator overflowed"),
+            denominator: i64::try_from(denominator / divisor)
+                .expect("Rational denominator overflowed"),
+        }
+    }
+}
+
+impl Zero for Rational {
+    fn zero() -> Self {
+        Self::from_integer(0)
+    }
+    fn is_zero(&self) -> bool {
+        self.numerator == 0
+    }
+}
+
+impl One for Rational {
+    fn one() -> Self {
+        Self::from_integer(1)
+    }
+}
+
+impl Num for Rational {
+    type FromStrRadixErr = core::num::ParseIntError;
+
+    /// Parses either an integer, or a fraction such as `-3/4`
+    fn from_str_radix(s: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        match s.find('/') {
+            Some(slash) => Ok(Self::new(
+                i64::from_str_radix(&s[..slash], radix)?,
+                i64::from_str_radix(&s[slash + 1..], radix)?,
+            )),
+            None => i64::from_str_radix(s, radix).map(Self::from_integer),
+        }
+    }
+}
+
+impl Add for Rational {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let numerator = (self.numerator as i128 * rhs.denominator as i128)
+            .checked_add(rhs.numerator as i128 * self.denominator as i128)
+            .expect("Rational numerator overflowed");
+        Self::reduced(
+            numerator,
+            self.denominator as i128 * rhs.denominator as i128,
+        )
+    }
+}
+impl Sub for Rational {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + -rhs
+    }
+}
+impl Mul for Rational {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::reduced(
+            self.numerator as i128 * rhs.numerator as i128,
+            self.denominator as i128 * rhs.denominator as i128,
+        )
+    }
+}
+impl Div for Rational {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        Self::reduced(
+            self.numerator as i128 * rhs.denominator as i128,
+            self.denominator as i128 * rhs.numerator as i128,
+        )
+    }
+}
+impl Rem for Rational {
+    type Output = Self;
+
+    /// The remainder left after truncated division
+    fn rem(self, rhs: Self) -> Self::Output {
+        let quotient = self / rhs;
+        let truncated = Self::from_integer(quotient.numerator / quotient.denominator);
+        self - rhs * truncated
+    }
+}
+impl Neg for Rational {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            numerator: self
+                .numerator
+                .checked_neg()
+                .expect("Rational numerator overflowed"),
+            denominator: self.denominator,
+        }
+    }
+}
+
+impl Pow<Rational> for Rational {
+    type Output = Self;
+
+    /// # Panics
+    /// If `rhs` isn't a whole number, as the result
+    /// would usually be irrational
+    fn pow(self, rhs: Rational) -> Self::Output {
+        assert!(
+            rhs.is_integer(),
+            "Rational can only be raised to a whole power"
+        );
+        let power = num_traits::pow(self, rhs.numerator.unsigned_abs() as usize);
+        if rhs.numerator < 0 {
+            Self::one() / power
+        } else {
+            power
+        }
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl Ord for Rational {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.numerator as i128 * other.denominator as i128)
+            .cmp(&(other.numerator as i128 * self.denominator as i128))
+    }
+}
+
+impl Debug for Rational {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self)
+    }
+}
+impl Display for Rational {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.denominator == 1 {
+            write!(f, "{}", self.numerator)
+        } else {
+            write!(f, "{}/{}", self.numerator, self.denominator)
+        }
+    }
+}
+
+/// An element of the finite field of integers modulo the prime `P`.
+///
+/// `P` isn't checked to be prime, but division is only
+/// meaningful when it is. A `P` of 0 or above `i64::MAX` fails to
+/// compile, however the first element is made
+/// ```compile_fail
+/// use cakcukus::exact::Gf;
+/// use num_traits::One;
+///
+/// let _ = Gf::<0>::one();
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Gf<const P: u64>(u64);
+
+impl<const P: u64> Gf<P> {
+    /// Stops `P` from being used if it's too large to reduce an i64 by
+    const VALID_MODULUS: () = assert!(P > 0 && P <= i64::MAX as u64, "Gf needs 0 < P <= i64::MAX");
+
+    /// Constructs a new element, reducing `value` modulo `P`
+    ///
+    /// Fails to compile if `P` is 0 or greater than `i64::MAX`
+    /// ```compile_fail
+    /// use cakcukus::exact::Gf;
+    ///
+    /// let _ = Gf::<{ u64::MAX - 58 }>::new(1);
+    /// ```
+    pub fn new(value: i64) -> Self {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::VALID_MODULUS;
+        Self(value.rem_euclid(P as i64) as u64)
+    }
+    /// The element's value, in the range `0..P`
+    pub fn value(&self) -> u64 {
+        self.0
+    }
+    /// Returns the multiplicative inverse, using Fermat's little theorem
+    ///
+    /// # Panics
+    /// If self is 0
+    pub fn inverse(&self) -> Self {
+        assert!(self.0 != 0, "0 has no inverse in Gf");
+        self.pow(Self(P - 2))
+    }
+}
+
+impl<const P: u64> Zero for Gf<P> {
+    fn zero() -> Self {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::VALID_MODULUS;
+        Self(0)
+    }
+    fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl<const P: u64> One for Gf<P> {
+    fn one() -> Self {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::VALID_MODULUS;
+        Self(1 % P)
+    }
+}
+
+impl<const P: u64> Num for Gf<P> {
+    type FromStrRadixErr = core::num::ParseIntError;
+
+    fn from_str_radix(s: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        i64::from_str_radix(s, radix).map(Self::new)
+    }
+}
+
+impl<const P: u64> Add for Gf<P> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(((self.0 as u128 + rhs.0 as u128) % P as u128) as u64)
+    }
+}
+impl<const P: u64> Sub for Gf<P> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + -rhs
+    }
+}
+impl<const P: u64> Mul for Gf<P> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self(((self.0 as u128 * rhs.0 as u128) % P as u128) as u64)
+    }
+}
+impl<const P: u64> Div for Gf<P> {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self::Output {
+        self * rhs.inverse()
+    }
+}
+impl<const P: u64> Rem for Gf<P> {
+    type Output = Self;
+
+    /// Division is always exact in a field, so this is always 0
+    fn rem(self, _rhs: Self) -> Self::Output {
+        Self(0)
+    }
+}
+impl<const P: u64> Neg for Gf<P> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self((P - self.0) % P)
+    }
+}
+
+impl<const P: u64> Pow<Gf<P>> for Gf<P> {
+    type Output = Self;
+
+    /// Raises self to the power of `rhs`'s value, by repeated squaring.
+    ///
+    /// Being an element of the field, the exponent is reduced modulo
+    /// `P`, so exponents of `P` or more can't be represented: as a
+    /// [Term](../term/struct.Term.html) exponent, `P` would raise x to
+    /// the 0th power rather than the `P`th. By Fermat's little theorem,
+    /// reducing such exponents modulo `P - 1` instead leaves the powers
+    /// of non-zero elements unchanged
+    fn pow(self, rhs: Gf<P>) -> Self::Output {
+        let mut base = self;
+        let mut exponent = rhs.0;
+        let mut result = Self::one();
+        while exponent != 0 {
+            if exponent & 1 == 1 {
+                result = result * base;
+            }
+            base = base * base;
+            exponent >>= 1;
+        }
+        result
+    }
+}
+
+impl<const P: u64> Debug for Gf<P> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+impl<const P: u64> Display for Gf<P> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
@@ -0,0 +1,273 @@
+//! Numerical differentiation of functions that can't be written as
+//! [Term](../term/struct.Term.html)s, with finite difference stencils
+//! and Richardson extrapolation.
+//!
+//! [NumericalFunction](struct.NumericalFunction.html) wraps a closure
+//! so it implements [Calculus](../traits/trait.Calculus.html), letting
+//! it be used wherever a symbolic type would be.
+
+use num_traits::{Float, Pow};
+
+use crate::{
+    quadrature::{gauss_kronrod, Estimate, QuadratureError},
+    traits::{Differentiation, Integration, TermTrait},
+    util::cast,
+};
+
+/// Intergrates `f` adaptively, settling for the best estimate
+/// found if the tolerance can't be reached
+fn quadrature<T: Float, F: Fn(T) -> T>(f: F, lower: T, upper: T) -> T {
+    let tolerance = T::epsilon().sqrt() * cast(1e-3);
+    match gauss_kronrod(f, lower, upper, tolerance, 200) {
+        Ok(estimate) | Err(QuadratureError::NotConverged(estimate)) => estimate.value,
+        Err(QuadratureError::Divergent) | Err(QuadratureError::OutsideDomain) => T::nan(),
+    }
+}
+
+/// The points a finite difference samples the function at
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stencil {
+    /// Samples at x, x + h, ..., x + nh, with an error of order h
+    Forward,
+    /// Samples at x, x - h, ..., x - nh, with an error of order h
+    Backward,
+    /// Samples symmetrically around x, with an error of order h^2
+    Central,
+    /// Applies the five point first derivative stencil n times,
+    /// with an error of order h^4
+    FivePoint,
+}
+
+impl Stencil {
+    /// The power of h the stencil's error is proportional to
+    pub fn accuracy(&self) -> i32 {
+        match self {
+            Stencil::Forward | Stencil::Backward => 1,
+            Stencil::Central => 2,
+            Stencil::FivePoint => 4,
+        }
+    }
+}
+
+/// Returns the nth finite difference of `f` at `x` with the step
+/// `step`, approximating the nth derivative
+pub fn difference<T: Float, F: Fn(T) -> T>(f: &F, x: T, n: usize, stencil: Stencil, step: T) -> T {
+    if n == 0 {
+        return f(x);
+    }
+    if stencil == Stencil::FivePoint {
+        let inner = |y: T| difference(f, y, n - 1, stencil, step);
+        let near = inner(x + step) - inner(x - step);
+        let far = inner(x + step + step) - inner(x - step - step);
+        return (cast::<T, _>(8.) * near - far) / (cast::<T, _>(12.) * step);
+    }
+    // Sums the binomially weighted samples, alternating in sign
+    let offset = match stencil {
+        Stencil::Forward => T::zero(),
+        Stencil::Backward => -cast::<T, _>(n as f64),
+        _ => -cast::<T, _>(n as f64) / cast(2.),
+    };
+    let mut total = T::zero();
+    let mut binomial = T::one();
+    for k in 0..=n {
+        let sample = f(x + (offset + cast(k as f64)) * step);
+        if (n - k) % 2 == 1 {
+            total = total - binomial * sample;
+        } else {
+            total = total + binomial * sample;
+        }
+        binomial = binomial * cast((n - k) as f64) / cast((k + 1) as f64);
+    }
+    total / step.powi(n as i32)
+}
+
+/// Chooses a step for the nth difference at `x` that balances
+/// truncation error against floating point rounding
+pub fn optimal_step<T: Float>(x: T, n: usize, stencil: Stencil) -> T {
+    let power = T::one() / cast((n as i32 + stencil.accuracy()) as f64);
+    T::epsilon().powf(power) * x.abs().max(T::one())
+}
+
+/// Approximates the nth derivative of `f` at `x` with `stencil`,
+/// choosing the step automatically
+/// ```
+/// use cakcukus::finite_difference::{derivative, Stencil};
+///
+/// let d = derivative(&|x: f64| x.sin(), 1., 1, Stencil::FivePoint);
+///
+/// assert!((d - 1f64.cos()).abs() < 1e-10);
+/// ```
+pub fn derivative<T: Float, F: Fn(T) -> T>(f: &F, x: T, n: usize, stencil: Stencil) -> T {
+    difference(f, x, n, stencil, optimal_step(x, n, stencil))
+}
+
+/// Approximates the nth derivative of `f` at `x` with Ridders' form
+/// of Richardson extrapolation, repeatedly shrinking the step of a
+/// central difference and extrapolating the results to a step of 0
+pub fn richardson<T: Float, F: Fn(T) -> T>(f: &F, x: T, n: usize) -> Estimate<T> {
+    const SHRINK: f64 = 1.4;
+    const STEPS: usize = 10;
+
+    if n == 0 {
+        return Estimate {
+            value: f(x),
+            error: T::zero(),
+        };
+    }
+    let shrink = cast::<T, _>(SHRINK);
+    let shrink_squared = shrink * shrink;
+    let mut step = cast::<T, _>(0.1 * n as f64) * x.abs().max(T::one());
+    let mut previous = vec![difference(f, x, n, Stencil::Central, step)];
+    let mut best = Estimate {
+        value: previous[0],
+        error: T::infinity(),
+    };
+    for i in 1..STEPS {
+        step = step / shrink;
+        let mut row = Vec::with_capacity(i + 1);
+        row.push(difference(f, x, n, Stencil::Central, step));
+        let mut factor = shrink_squared;
+        for j in 1..=i {
+            let extrapolated = (row[j - 1] * factor - previous[j - 1]) / (factor - T::one());
+            factor = factor * shrink_squared;
+            let error = (extrapolated - row[j - 1])
+                .abs()
+                .max((extrapolated - previous[j - 1]).abs());
+            if error <= best.error {
+                best = Estimate {
+                    value: extrapolated,
+                    error,
+                };
+            }
+            row.push(extrapolated);
+        }
+        // Stops once rounding error makes the tableau worse
+        if (row[i] - previous[i - 1]).abs() >= cast::<T, _>(2.) * best.error {
+            break;
+        }
+        previous = row;
+    }
+    best
+}
+
+/// A function known only through a closure, differentiated and
+/// intergrated numerically.
+///
+/// Differentiating or intergrating only changes the order of the
+/// derivative being described, so the error doesn't build up
+/// through repeated calls. Derivatives are found with
+/// [richardson](fn.richardson.html), and antiderivatives, which are
+/// taken to be 0 at `x = 0`, with adaptive quadrature
+///
+/// # Examples
+/// ```
+/// use cakcukus::{
+///     finite_difference::NumericalFunction,
+///     traits::{Differentiation, TermTrait},
+/// };
+///
+/// let exp = NumericalFunction::new(|x: f64| x.exp());
+///
+/// let third = exp.nth_derivative(3);
+///
+/// assert!((third.sum_with_respect_to(&1.) - 1f64.exp()).abs() < 1e-6);
+/// ```
+#[derive(Clone, Copy)]
+pub struct NumericalFunction<F> {
+    function: F,
+    order: i32,
+}
+
+impl<F> NumericalFunction<F> {
+    /// Wraps `function`
+    pub fn new(function: F) -> Self {
+        Self { function, order: 0 }
+    }
+    /// The order of the derivative described, with negative
+    /// orders being repeated antiderivatives
+    pub fn order(&self) -> i32 {
+        self.order
+    }
+    /// Returns the wrapped closure
+    pub fn function(&self) -> &F {
+        &self.function
+    }
+    fn with_order(&self, order: i32) -> Self
+    where
+        F: Clone,
+    {
+        Self {
+            function: self.function.clone(),
+            order,
+        }
+    }
+    /// Evaluates the derivative of the given order at `x`
+    fn evaluate<T>(&self, order: i32, x: T) -> T
+    where
+        T: Float,
+        F: Fn(T) -> T,
+    {
+        if order >= 0 {
+            return richardson(&self.function, x, order as usize).value;
+        }
+        // Cauchy's formula for repeated integration turns the mth
+        // antiderivative into the single integral of
+        // (x - t)^(m - 1) / (m - 1)! * f(t) from 0 to x
+        let m = -order;
+        let mut factorial = T::one();
+        for k in 1..m {
+            factorial = factorial * cast(k as f64);
+        }
+        let integrand = |t: T| (x - t).powi(m - 1) / factorial * (self.function)(t);
+        quadrature(integrand, T::zero(), x)
+    }
+}
+
+impl<T, F> TermTrait<T> for NumericalFunction<F>
+where
+    T: Float + Pow<T, Output = T>,
+    F: Fn(T) -> T,
+{
+    fn sum_with_respect_to(&self, x: &T) -> T {
+        self.evaluate(self.order, *x)
+    }
+}
+
+impl<T, F> Differentiation<T> for NumericalFunction<F>
+where
+    T: Float + Pow<T, Output = T>,
+    F: Fn(T) -> T + Clone,
+{
+    fn differentiate(&self, x: &T) -> T {
+        self.evaluate(self.order + 1, *x)
+    }
+    fn differentiate_self(&self) -> Self {
+        self.with_order(self.order + 1)
+    }
+    fn nth_derivative(&self, n: usize) -> Self {
+        self.with_order(self.order + n as i32)
+    }
+    fn differentiate_nth(&self, n: usize, x: &T) -> T {
+        self.evaluate(self.order + n as i32, *x)
+    }
+}
+
+impl<T, F> Integration<T> for NumericalFunction<F>
+where
+    T: Float + Pow<T, Output = T>,
+    F: Fn(T) -> T + Clone,
+{
+    fn integrate_self(&self) -> Self {
+        self.with_order(self.order - 1)
+    }
+    fn integrate(&self, lower: T, upper: T) -> T {
+        if self.order == 0 {
+            quadrature(&self.function, lower, upper)
+        } else {
+            self.evaluate(self.order - 1, upper) - self.evaluate(self.order - 1, lower)
+        }
+    }
+    fn nth_antiderivative(&self, n: usize) -> Self {
+        self.with_order(self.order - n as i32)
+    }
+}
@@ -0,0 +1,172 @@
+//! Least squares fitting of a [Polynomial](../struct.Polynomial.html)
+//! to noisy data, solved by QR decomposition rather than the normal
+//! equations, which lose twice as many digits.
+
+use core::fmt::Display;
+
+use num_traits::{Float, Pow};
+
+use crate::{linalg, traits::TermTrait, util::cast, Polynomial};
+
+/// The reasons a polynomial couldn't be fitted
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FitError {
+    /// A polynomial of degree n needs at least n + 1 points with a
+    /// positive weight
+    TooFewPoints,
+    /// The x values don't determine the coefficients, such as when
+    /// there are fewer distinct x values than coefficients
+    RankDeficient,
+}
+
+impl Display for FitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FitError::TooFewPoints => write!(f, "not enough points for the degree"),
+            FitError::RankDeficient => write!(f, "the points don't determine a unique fit"),
+        }
+    }
+}
+
+impl std::error::Error for FitError {}
+
+/// A fitted polynomial, along with how well it fits
+#[derive(Debug, Clone, PartialEq)]
+pub struct Fit<T>
+where
+    T: Float + Pow<T, Output = T>,
+{
+    /// The polynomial minimising the (weighted) sum of squared
+    /// residuals
+    pub polynomial: Polynomial<T>,
+
+    /// `y - polynomial(x)` for each point, in the order given
+    pub residuals: Vec<T>,
+
+    /// The coefficient of determination, being 1 for a perfect fit
+    pub r_squared: T,
+
+    /// The standard error of each coefficient, with the nth belonging
+    /// to x^n. This is None when there are no more points with a
+    /// positive weight than coefficients, as the noise can't then be
+    /// estimated
+    pub standard_errors: Option<Vec<T>>,
+}
+
+impl<T> Polynomial<T>
+where
+    T: Float + Pow<T, Output = T>,
+{
+    /// Fits a polynomial of at most `degree` to the points `(xs, ys)`
+    /// by least squares
+    ///
+    /// # Panics
+    /// If `xs` and `ys` have different lengths
+    /// ```
+    /// use cakcukus::{traits::TermTrait, Polynomial};
+    ///
+    /// let xs = [0f64, 1., 2., 3.];
+    /// let ys = [1.1, 2.9, 5.1, 6.9]; // Roughly 2x + 1
+    /// let fit = Polynomial::fit(&xs, &ys, 1).unwrap();
+    ///
+    /// assert!((fit.polynomial.sum_with_respect_to(&10.) - 20.66).abs() < 1e-10);
+    /// assert!(fit.r_squared > 0.99);
+    /// ```
+    pub fn fit(xs: &[T], ys: &[T], degree: usize) -> Result<Fit<T>, FitError> {
+        Self::fit_weighted(xs, ys, &vec![T::one(); xs.len()], degree)
+    }
+    /// Fits a polynomial of at most `degree` to the points `(xs, ys)`,
+    /// minimising the sum of each squared residual times its weight.
+    /// Weights are usually `1 / variance` of each y value, and points
+    /// with a weight of 0 don't count towards the points needed
+    ///
+    /// # Panics
+    /// If `xs`, `ys` and `weights` have different lengths, or any
+    /// weight is negative
+    pub fn fit_weighted(
+        xs: &[T],
+        ys: &[T],
+        weights: &[T],
+        degree: usize,
+    ) -> Result<Fit<T>, FitError> {
+        assert!(
+            xs.len() == ys.len() && xs.len() == weights.len(),
+            "Every point needs an x, a y and a weight"
+        );
+        assert!(
+            weights.iter().all(|w| *w >= T::zero()),
+            "Weights can't be negative"
+        );
+        let coefficients = degree + 1;
+        let weighted = weights.iter().filter(|w| **w > T::zero()).count();
+        if weighted < coefficients {
+            return Err(FitError::TooFewPoints);
+        }
+
+        // Scaling each row by the root of its weight turns the
+        // weighted problem into an ordinary one
+        let mut vandermonde = Vec::with_capacity(xs.len());
+        let mut rhs = Vec::with_capacity(xs.len());
+        for ((x, y), w) in xs.iter().zip(ys.iter()).zip(weights.iter()) {
+            let root = w.sqrt();
+            let mut row = Vec::with_capacity(coefficients);
+            let mut power = root;
+            for _ in 0..coefficients {
+                row.push(power);
+                power = power * *x;
+            }
+            vandermonde.push(row);
+            rhs.push(*y * root);
+        }
+        let solved = linalg::least_squares(vandermonde, rhs).ok_or(FitError::RankDeficient)?;
+        let polynomial = Polynomial::from_coefficients(&solved.solution);
+
+        let residuals: Vec<T> = xs
+            .iter()
+            .zip(ys.iter())
+            .map(|(x, y)| *y - polynomial.sum_with_respect_to(x))
+            .collect();
+        let total_weight = weights.iter().fold(T::zero(), |total, w| total + *w);
+        let mean = ys
+            .iter()
+            .zip(weights.iter())
+            .fold(T::zero(), |total, (y, w)| total + *y * *w)
+            / total_weight;
+        let mut residual_squares = T::zero();
+        let mut total_squares = T::zero();
+        for ((y, r), w) in ys.iter().zip(residuals.iter()).zip(weights.iter()) {
+            residual_squares = residual_squares + *w * *r * *r;
+            total_squares = total_squares + *w * (*y - mean) * (*y - mean);
+        }
+        let r_squared = if total_squares.is_zero() {
+            T::one()
+        } else {
+            T::one() - residual_squares / total_squares
+        };
+
+        // The covariance of the coefficients is
+        // sigma^2 (A^T A)^-1 = sigma^2 R^-1 R^-T
+        let degrees_of_freedom = weighted - coefficients;
+        let standard_errors = if degrees_of_freedom == 0 {
+            None
+        } else {
+            let variance = residual_squares / cast::<T, _>(degrees_of_freedom);
+            let errors = solved
+                .r_inverse
+                .iter()
+                .map(|row| {
+                    let norm_squared = row.iter().fold(T::zero(), |total, x| total + *x * *x);
+                    (variance * norm_squared).sqrt()
+                })
+                .collect();
+            Some(errors)
+        };
+
+        Ok(Fit {
+            polynomial,
+            residuals,
+            r_squared,
+            standard_errors,
+        })
+    }
+}
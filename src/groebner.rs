@@ -0,0 +1,275 @@
+//! Gröbner bases of ideals generated by
+//! [MultiPolynomial](../struct.MultiPolynomial.html)s, computed
+//! with Buchberger's algorithm.
+//!
+//! The coefficients must form a field, and should be exact, such as
+//! [Rational](../exact/struct.Rational.html) or [Gf](../exact/struct.Gf.html),
+//! as the algorithm relies on terms cancelling to exactly 0.
+
+use num_traits::{identities::zero, Num};
+
+use crate::{MonomialOrder, MultiPolynomial, MultiTerm};
+
+/// Returns true if the monomial `a` divides the monomial `b`
+fn divides(a: &[u32], b: &[u32]) -> bool {
+    a.iter()
+        .enumerate()
+        .all(|(i, e)| *e <= b.get(i).copied().unwrap_or(0))
+}
+
+fn lcm(a: &[u32], b: &[u32]) -> Vec<u32> {
+    (0..a.len().max(b.len()))
+        .map(|i| {
+            let e = a.get(i).copied().unwrap_or(0);
+            e.max(b.get(i).copied().unwrap_or(0))
+        })
+        .collect()
+}
+
+/// Returns `b / a`, assuming `a` divides `b`
+fn quotient(b: &[u32], a: &[u32]) -> Vec<u32> {
+    b.iter()
+        .enumerate()
+        .map(|(i, e)| e - a.get(i).copied().unwrap_or(0))
+        .collect()
+}
+
+/// Returns `p - term * q`, simplified by `order`
+fn subtract_multiple<T: Num + Clone>(
+    p: &MultiPolynomial<T>,
+    term: &MultiTerm<T>,
+    q: &MultiPolynomial<T>,
+    order: MonomialOrder,
+) -> MultiPolynomial<T> {
+    let mut result = p.clone();
+    for q_term in q.0.iter() {
+        let mut product = q_term.clone() * term.clone();
+        product.coefficient = zero::<T>() - product.coefficient;
+        result.0.push(product);
+    }
+    result.simplify_by(order);
+    result
+}
+
+/// Divides the leading coefficient out of `p`, assuming
+/// `p` is simplified by `order`
+fn make_monic<T: Num + Clone>(p: &mut MultiPolynomial<T>) {
+    if let Some(leading) = p.0.first().map(|t| t.coefficient.clone()) {
+        for term in p.0.iter_mut() {
+            term.coefficient = term.coefficient.clone() / leading.clone();
+        }
+    }
+}
+
+/// Returns the remainder of `p` after multivariate division by
+/// every polynomial in `divisors`, so that no term of the
+/// remainder is divisible by a leading term of a divisor
+pub fn reduce<T: Num + Clone>(
+    p: &MultiPolynomial<T>,
+    divisors: &[MultiPolynomial<T>],
+    order: MonomialOrder,
+) -> MultiPolynomial<T> {
+    let mut divisors = divisors.to_vec();
+    for divisor in divisors.iter_mut() {
+        divisor.simplify_by(order);
+    }
+    divisors.retain(|d| !d.0.is_empty());
+
+    let mut p = p.clone();
+    p.simplify_by(order);
+    let mut remainder = MultiPolynomial::new();
+
+    while !p.0.is_empty() {
+        let leading = p.0[0].clone();
+        match divisors
+            .iter()
+            .find(|d| divides(&d.0[0].exponents, &leading.exponents))
+        {
+            Some(divisor) => {
+                let factor = MultiTerm::new(
+                    leading.coefficient / divisor.0[0].coefficient.clone(),
+                    quotient(&leading.exponents, &divisor.0[0].exponents),
+                );
+                p = subtract_multiple(&p, &factor, divisor, order);
+            }
+            None => {
+                remainder.0.push(leading);
+                p.0.remove(0);
+            }
+        }
+    }
+    remainder
+}
+
+/// Returns the S-polynomial of `p` and `q`, assuming both are
+/// simplified by `order` and not empty
+fn s_polynomial<T: Num + Clone>(
+    p: &MultiPolynomial<T>,
+    q: &MultiPolynomial<T>,
+    order: MonomialOrder,
+) -> MultiPolynomial<T> {
+    let lcm = lcm(&p.0[0].exponents, &q.0[0].exponents);
+    let p_factor = MultiTerm::new(
+        T::one() / p.0[0].coefficient.clone(),
+        quotient(&lcm, &p.0[0].exponents),
+    );
+    let q_factor = MultiTerm::new(
+        T::one() / q.0[0].coefficient.clone(),
+        quotient(&lcm, &q.0[0].exponents),
+    );
+    let mut scaled = p.clone() * p_factor;
+    scaled.simplify_by(order);
+    subtract_multiple(&scaled, &q_factor, q, order)
+}
+
+/// Computes the reduced Gröbner basis of the ideal generated by
+/// `generators`, using Buchberger's algorithm. Every polynomial in
+/// the result is monic, and they're sorted by their leading terms,
+/// from largest to smallest
+///
+/// # Examples
+/// ```
+/// use cakcukus::{exact::Rational, groebner::groebner_basis, MonomialOrder, MultiPolynomial, MultiTerm};
+///
+/// let r = Rational::from_integer;
+///
+/// // x^2 + y^2 - 1 and x - y
+/// let generators = vec![
+///     MultiPolynomial(vec![
+///         MultiTerm::new(r(1), vec![2]),
+///         MultiTerm::new(r(1), vec![0, 2]),
+///         MultiTerm::new(r(-1), vec![]),
+///     ]),
+///     MultiPolynomial(vec![MultiTerm::new(r(1), vec![1]), MultiTerm::new(r(-1), vec![0, 1])]),
+/// ];
+///
+/// let basis = groebner_basis(&generators, MonomialOrder::Lex);
+///
+/// // x - y and y^2 - 1/2
+/// assert_eq!(basis.len(), 2);
+/// assert_eq!(
+///     basis[1],
+///     MultiPolynomial(vec![MultiTerm::new(r(1), vec![0, 2]), MultiTerm::new(Rational::new(-1, 2), vec![])])
+/// );
+/// ```
+pub fn groebner_basis<T: Num + Clone>(
+    generators: &[MultiPolynomial<T>],
+    order: MonomialOrder,
+) -> Vec<MultiPolynomial<T>> {
+    let mut basis: Vec<MultiPolynomial<T>> = Vec::with_capacity(generators.len());
+    for generator in generators {
+        let mut generator = generator.clone();
+        generator.simplify_by(order);
+        if !generator.0.is_empty() {
+            basis.push(generator);
+        }
+    }
+
+    let mut pairs: Vec<(usize, usize)> = Vec::new();
+    for j in 0..basis.len() {
+        for i in 0..j {
+            pairs.push((i, j));
+        }
+    }
+
+    while let Some((i, j)) = pairs.pop() {
+        // Buchberger's first criterion, the S-polynomial of two
+        // polynomials with coprime leading terms always reduces to 0
+        let coprime = basis[i].0[0]
+            .exponents
+            .iter()
+            .zip(basis[j].0[0].exponents.iter())
+            .all(|(a, b)| *a == 0 || *b == 0);
+        if coprime {
+            continue;
+        }
+        let remainder = reduce(&s_polynomial(&basis[i], &basis[j], order), &basis, order);
+        if !remainder.0.is_empty() {
+            for k in 0..basis.len() {
+                pairs.push((k, basis.len()));
+            }
+            basis.push(remainder);
+        }
+    }
+
+    // Removes any polynomial whose leading term is divisible by
+    // another's, keeping the first of any with equal leading terms
+    let mut minimal: Vec<MultiPolynomial<T>> = Vec::with_capacity(basis.len());
+    for (i, p) in basis.iter().enumerate() {
+        let redundant = basis.iter().enumerate().any(|(j, q)| {
+            i != j
+                && divides(&q.0[0].exponents, &p.0[0].exponents)
+                && (q.0[0].exponents != p.0[0].exponents || j < i)
+        });
+        if !redundant {
+            minimal.push(p.clone());
+        }
+    }
+
+    // Reduces each polynomial by the rest, leaving the leading
+    // terms alone as none divide each other
+    let mut reduced = Vec::with_capacity(minimal.len());
+    for i in 0..minimal.len() {
+        let others: Vec<MultiPolynomial<T>> = minimal
+            .iter()
+            .enumerate()
+            .filter(|(j, _)| *j != i)
+            .map(|(_, q)| q.clone())
+            .collect();
+        let mut p = reduce(&minimal[i], &others, order);
+        make_monic(&mut p);
+        reduced.push(p);
+    }
+    reduced.sort_by(|a, b| order.compare(&b.0[0].exponents, &a.0[0].exponents));
+    reduced
+}
+
+/// An ideal, described by its reduced Gröbner basis
+#[derive(Debug, Clone, PartialEq)]
+pub struct Ideal<T>
+where
+    T: Num + Clone,
+{
+    /// The reduced Gröbner basis of the ideal
+    pub basis: Vec<MultiPolynomial<T>>,
+
+    /// The order the basis was computed with
+    pub order: MonomialOrder,
+}
+
+impl<T: Num + Clone> Ideal<T> {
+    /// Constructs the ideal generated by `generators`
+    pub fn new(generators: &[MultiPolynomial<T>], order: MonomialOrder) -> Self {
+        Self {
+            basis: groebner_basis(generators, order),
+            order,
+        }
+    }
+    /// Returns the unique remainder of `p` when divided by the basis
+    pub fn reduce(&self, p: &MultiPolynomial<T>) -> MultiPolynomial<T> {
+        reduce(p, &self.basis, self.order)
+    }
+    /// Returns true if `p` is a member of the ideal
+    pub fn contains(&self, p: &MultiPolynomial<T>) -> bool {
+        self.reduce(p).0.is_empty()
+    }
+    /// Returns true if the ideal contains 1, meaning the
+    /// generators have no common solution
+    pub fn is_trivial(&self) -> bool {
+        self.basis.len() == 1 && self.basis[0].total_degree() == 0
+    }
+    /// Returns the polynomials of the basis that don't use any of
+    /// the first `k` variables. When the order is `Lex` or
+    /// `Elimination(k)`, these form a basis of the elimination ideal,
+    /// which lets triangular systems be solved one variable at a time
+    pub fn eliminate(&self, k: usize) -> Vec<MultiPolynomial<T>> {
+        self.basis
+            .iter()
+            .filter(|p| {
+                p.0.iter()
+                    .all(|t| t.exponents.iter().take(k).all(|e| *e == 0))
+            })
+            .cloned()
+            .collect()
+    }
+}
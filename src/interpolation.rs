@@ -0,0 +1,255 @@
+//! Polynomials passing through given points, by Lagrange's formula,
+//! Newton's divided differences, or Hermite's method when the
+//! derivatives at each point are known too.
+
+use num_traits::{
+    identities::{one, zero},
+    Num, Pow,
+};
+
+use crate::{traits::TermTrait, util::from_u32, Polynomial};
+
+/// Multiplies the coefficients of a polynomial, lowest power first,
+/// by `(x - root)`
+fn multiply_linear<T: Num + Copy>(coefficients: &[T], root: T) -> Vec<T> {
+    let mut product = vec![zero(); coefficients.len() + 1];
+    for (power, coefficient) in coefficients.iter().enumerate() {
+        product[power + 1] = product[power + 1] + *coefficient;
+        product[power] = product[power] - *coefficient * root;
+    }
+    product
+}
+
+/// # Panics
+/// If two of the x values are the same
+fn assert_distinct<T: PartialEq>(xs: &[T]) {
+    for (i, x) in xs.iter().enumerate() {
+        assert!(
+            !xs[..i].contains(x),
+            "Interpolation points must have distinct x values"
+        );
+    }
+}
+
+/// An interpolating polynomial in Newton's form,
+/// `c0 + c1(x - x0) + c2(x - x0)(x - x1) + ...`, which can be
+/// evaluated stably, and extended by another point without
+/// starting over
+#[derive(Debug, Clone, PartialEq)]
+pub struct NewtonForm<T>
+where
+    T: Num + Pow<T, Output = T> + Copy + PartialOrd,
+{
+    nodes: Vec<T>,
+    coefficients: Vec<T>,
+    /// The last row of the divided difference table, being
+    /// `f[xn], f[xn-1, xn], ..., f[x0, ..., xn]`
+    diagonal: Vec<T>,
+}
+
+impl<T> NewtonForm<T>
+where
+    T: Num + Pow<T, Output = T> + Copy + PartialOrd,
+{
+    /// Builds the interpolating polynomial through the `(x, y)` points
+    ///
+    /// # Panics
+    /// If two of the points share an x value
+    /// ```
+    /// use cakcukus::{interpolation::NewtonForm, traits::TermTrait};
+    ///
+    /// let mut newton = NewtonForm::new(&[(0., 1.), (1., 2.)]);
+    /// newton.add_point(2., 5.); // Now x^2 + 1
+    ///
+    /// assert_eq!(newton.sum_with_respect_to(&3.), 10.);
+    /// ```
+    pub fn new(points: &[(T, T)]) -> Self {
+        let mut newton = Self {
+            nodes: Vec::with_capacity(points.len()),
+            coefficients: Vec::with_capacity(points.len()),
+            diagonal: Vec::with_capacity(points.len()),
+        };
+        for (x, y) in points {
+            newton.add_point(*x, *y);
+        }
+        newton
+    }
+    /// Builds the polynomial of lowest degree matching the values
+    /// and derivatives given at each point, with each point being
+    /// `(x, [f(x), f'(x), f''(x), ...])`. Nodes with derivatives
+    /// appear once per known value
+    ///
+    /// # Panics
+    /// If two of the points share an x value, or a point has no
+    /// values
+    pub fn hermite(points: &[(T, Vec<T>)]) -> Self {
+        let xs: Vec<T> = points.iter().map(|(x, _)| *x).collect();
+        assert_distinct(&xs);
+        assert!(
+            points.iter().all(|(_, values)| !values.is_empty()),
+            "Every Hermite point needs at least a value"
+        );
+        if points.is_empty() {
+            return Self::new(&[]);
+        }
+
+        // Each x is repeated once per known value, with derivatives
+        // standing in for divided differences between equal nodes
+        let mut nodes = Vec::new();
+        let mut values = Vec::new();
+        for (x, known) in points {
+            for _ in 0..known.len() {
+                nodes.push(*x);
+                values.push(known);
+            }
+        }
+
+        let mut column: Vec<T> = values.iter().map(|known| known[0]).collect();
+        let mut coefficients = vec![column[0]];
+        let mut diagonal = vec![column[column.len() - 1]];
+        let mut factorial = one::<T>();
+        for k in 1..nodes.len() {
+            factorial = factorial * from_u32(k as u32);
+            let mut next = Vec::with_capacity(column.len() - 1);
+            for i in k..nodes.len() {
+                if nodes[i] == nodes[i - k] {
+                    // The node appears k + 1 times in a row, so its
+                    // kth derivative is known
+                    next.push(values[i][k] / factorial);
+                } else {
+                    next.push((column[i - k + 1] - column[i - k]) / (nodes[i] - nodes[i - k]));
+                }
+            }
+            coefficients.push(next[0]);
+            diagonal.push(next[next.len() - 1]);
+            column = next;
+        }
+        Self {
+            nodes,
+            coefficients,
+            diagonal,
+        }
+    }
+    /// Extends the polynomial to also pass through `(x, y)`, raising
+    /// its degree by one
+    ///
+    /// # Panics
+    /// If a point with the same x value has already been added
+    pub fn add_point(&mut self, x: T, y: T) {
+        assert!(
+            !self.nodes.contains(&x),
+            "Interpolation points must have distinct x values"
+        );
+        let mut diagonal = Vec::with_capacity(self.diagonal.len() + 1);
+        diagonal.push(y);
+        for (k, previous) in self.diagonal.iter().enumerate() {
+            let node = self.nodes[self.nodes.len() - 1 - k];
+            diagonal.push((diagonal[k] - *previous) / (x - node));
+        }
+        self.coefficients.push(*diagonal.last().unwrap());
+        self.nodes.push(x);
+        self.diagonal = diagonal;
+    }
+    /// The x values the polynomial is centred on, in the order they
+    /// were added
+    pub fn nodes(&self) -> &[T] {
+        &self.nodes
+    }
+    /// The divided differences `f[x0], f[x0, x1], ...`
+    pub fn coefficients(&self) -> &[T] {
+        &self.coefficients
+    }
+    /// Expands the Newton form into a simplified polynomial
+    pub fn to_polynomial(&self) -> Polynomial<T> {
+        let mut expanded: Vec<T> = Vec::with_capacity(self.coefficients.len());
+        for (coefficient, node) in self.coefficients.iter().zip(self.nodes.iter()).rev() {
+            expanded = multiply_linear(&expanded, *node);
+            if expanded.is_empty() {
+                expanded.push(zero());
+            }
+            expanded[0] = expanded[0] + *coefficient;
+        }
+        Polynomial::from_coefficients(&expanded)
+    }
+}
+
+impl<T> TermTrait<T> for NewtonForm<T>
+where
+    T: Num + Pow<T, Output = T> + Copy + PartialOrd,
+{
+    /// Evaluates the nested form, `c0 + (x - x0)(c1 + (x - x1)(...))`
+    fn sum_with_respect_to(&self, x: &T) -> T {
+        let mut total = zero();
+        for (coefficient, node) in self.coefficients.iter().zip(self.nodes.iter()).rev() {
+            total = total * (*x - *node) + *coefficient;
+        }
+        total
+    }
+}
+
+impl<T> Polynomial<T>
+where
+    T: Num + Pow<T, Output = T> + Copy + PartialOrd,
+{
+    /// Returns the polynomial of lowest degree through the `(x, y)`
+    /// points, by summing Lagrange's basis polynomials
+    ///
+    /// # Panics
+    /// If two of the points share an x value
+    /// ```
+    /// use cakcukus::{exact::Rational, terms, Polynomial};
+    ///
+    /// let r = Rational::from_integer;
+    /// let expected = terms!(r(1), r(2), r(1), r(0)); // x^2 + 1
+    ///
+    /// assert_eq!(Polynomial::lagrange(&[(r(-1), r(2)), (r(0), r(1)), (r(2), r(5))]), expected);
+    /// ```
+    pub fn lagrange(points: &[(T, T)]) -> Self {
+        let xs: Vec<T> = points.iter().map(|(x, _)| *x).collect();
+        assert_distinct(&xs);
+        let mut total = vec![zero(); points.len()];
+        for (i, (xi, yi)) in points.iter().enumerate() {
+            let mut basis = vec![one()];
+            let mut denominator = one::<T>();
+            for (j, xj) in xs.iter().enumerate() {
+                if i != j {
+                    basis = multiply_linear(&basis, *xj);
+                    denominator = denominator * (*xi - *xj);
+                }
+            }
+            for (sum, b) in total.iter_mut().zip(basis.iter()) {
+                *sum = *sum + *yi * *b / denominator;
+            }
+        }
+        Self::from_coefficients(&total)
+    }
+    /// Returns the polynomial of lowest degree through the `(x, y)`
+    /// points, by Newton's divided differences. Use
+    /// [NewtonForm](interpolation/struct.NewtonForm.html) directly to
+    /// keep the nested form, or to add points later
+    ///
+    /// # Panics
+    /// If two of the points share an x value
+    pub fn newton(points: &[(T, T)]) -> Self {
+        NewtonForm::new(points).to_polynomial()
+    }
+    /// Returns the polynomial of lowest degree matching the values
+    /// and derivatives given at each point, with each point being
+    /// `(x, [f(x), f'(x), f''(x), ...])`
+    ///
+    /// # Panics
+    /// If two of the points share an x value, or a point has no
+    /// values
+    /// ```
+    /// use cakcukus::{traits::{Differentiation, TermTrait}, Polynomial};
+    ///
+    /// // Passes through (0, 0) and (1, 1), flat at both
+    /// let smoothstep = Polynomial::hermite(&[(0., vec![0., 0.]), (1., vec![1., 0.])]);
+    ///
+    /// assert_eq!(smoothstep.sum_with_respect_to(&0.5), 0.5);
+    /// assert_eq!(smoothstep.differentiate(&1.), 0.);
+    /// ```
+    pub fn hermite(points: &[(T, Vec<T>)]) -> Self {
+        NewtonForm::hermite(points).to_polynomial()
+    }
+}
@@ -2,16 +2,69 @@
 //! The crate is build around the type [Term](term/struct.Term.html),
 //! which is used to represent some `Coefficient * x ^ Exponent`.
 
+#[cfg(feature = "built_in_types")]
+pub mod bernstein;
+#[cfg(feature = "built_in_types")]
+pub mod chebyshev;
+#[cfg(feature = "built_in_types")]
+mod composition;
+#[cfg(feature = "built_in_types")]
+pub mod exact;
+#[cfg(feature = "built_in_types")]
+pub mod fit;
+#[cfg(feature = "built_in_types")]
+pub mod groebner;
+#[cfg(feature = "built_in_types")]
+pub mod interpolation;
+#[cfg(feature = "built_in_types")]
+pub mod limit;
+#[cfg(feature = "built_in_types")]
+mod linalg;
+#[cfg(feature = "built_in_types")]
+pub mod matrix;
+#[cfg(feature = "built_in_types")]
+pub mod multivariate;
+#[cfg(feature = "built_in_types")]
+pub mod orthogonal;
+#[cfg(feature = "built_in_types")]
+pub mod pade;
 #[cfg(feature = "built_in_types")]
 mod polynomial;
 #[cfg(feature = "built_in_types")]
+mod power_series;
+#[cfg(feature = "built_in_types")]
+mod rational_function;
+#[cfg(feature = "built_in_types")]
+pub mod remez;
+#[cfg(feature = "built_in_types")]
+pub mod resultant;
+#[cfg(feature = "built_in_types")]
+pub mod roots;
+#[cfg(feature = "built_in_types")]
+pub mod spline;
+#[cfg(feature = "built_in_types")]
+pub mod taylor;
+#[cfg(feature = "built_in_types")]
 pub mod term;
 
+pub mod analysis;
+pub mod finite_difference;
+pub mod piecewise;
+pub mod quadrature;
 pub mod traits;
+mod util;
 
+#[cfg(feature = "built_in_types")]
+pub use multivariate::{jacobian, jacobian_at, MonomialOrder, MultiPolynomial, MultiTerm};
 #[cfg(feature = "built_in_types")]
 pub use polynomial::Polynomial;
 #[cfg(feature = "built_in_types")]
+pub use power_series::PowerSeries;
+#[cfg(feature = "built_in_types")]
+pub use rational_function::RationalFunction;
+#[cfg(feature = "built_in_types")]
+pub use spline::Spline;
+#[cfg(feature = "built_in_types")]
 pub use term::Term;
 
 pub use traits::Calculus;
@@ -0,0 +1,267 @@
+//! Limits of functions as x approaches a point or infinity, found by
+//! leading term analysis for [Polynomial](../struct.Polynomial.html)s
+//! and [RationalFunction](../struct.RationalFunction.html)s, and by
+//! L'Hôpital's rule for any other [Differentiation](../traits/trait.Differentiation.html)
+//! implementor.
+
+use num_traits::{Float, Pow};
+
+use crate::{
+    traits::{Differentiation, TermTrait},
+    util::cast,
+    Polynomial, RationalFunction, Term,
+};
+
+/// The most derivatives L'Hôpital's rule will take before
+/// giving up
+const MAX_ORDER: usize = 64;
+
+/// The value a function approaches
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Limit<T> {
+    /// The function approaches a finite value
+    Finite(T),
+    /// The function grows without bound
+    PositiveInfinity,
+    /// The function falls without bound
+    NegativeInfinity,
+    /// The function doesn't settle, or approaches different values
+    /// from either side
+    DoesNotExist,
+}
+
+impl<T: Float> Limit<T> {
+    /// Returns an infinite limit with the sign of `sign`
+    fn infinite(sign: T) -> Self {
+        if sign > T::zero() {
+            Limit::PositiveInfinity
+        } else {
+            Limit::NegativeInfinity
+        }
+    }
+    /// Returns the negated limit
+    fn negate(self) -> Self {
+        match self {
+            Limit::Finite(value) => Limit::Finite(-value),
+            Limit::PositiveInfinity => Limit::NegativeInfinity,
+            Limit::NegativeInfinity => Limit::PositiveInfinity,
+            Limit::DoesNotExist => Limit::DoesNotExist,
+        }
+    }
+}
+
+fn is_whole<T: Float>(x: T) -> bool {
+    x.fract().is_zero()
+}
+
+fn is_odd<T: Float>(x: T) -> bool {
+    !(x / (T::one() + T::one())).fract().is_zero()
+}
+
+/// Returns the limit of `coefficient * x^exponent` as x approaches
+/// `a`, which must be infinite or 0
+fn power_limit<T: Float>(coefficient: T, exponent: T, a: T) -> Limit<T> {
+    if coefficient.is_zero() || exponent.is_zero() {
+        return Limit::Finite(coefficient);
+    }
+    // Negative x can only be raised to whole powers
+    let from_below = a < T::zero() || a.is_zero();
+    if from_below && !is_whole(exponent) {
+        return Limit::DoesNotExist;
+    }
+    let shrinking = if a.is_infinite() {
+        exponent < T::zero()
+    } else {
+        exponent > T::zero()
+    };
+    if shrinking {
+        return Limit::Finite(T::zero());
+    }
+    if a.is_zero() && is_odd(exponent) {
+        // Blows up with opposite signs on either side of 0
+        return Limit::DoesNotExist;
+    }
+    let limit = Limit::infinite(coefficient);
+    if a < T::zero() && is_odd(exponent) {
+        limit.negate()
+    } else {
+        limit
+    }
+}
+
+/// Returns the limit of `numerator(x) / denominator(x)` as x
+/// approaches the finite value `a`, using L'Hôpital's rule whenever
+/// both approach 0. Values within `tolerance` of 0 count as 0, so
+/// numerical implementors can be used too
+///
+/// # Panics
+/// If `a` isn't finite
+/// ```
+/// use cakcukus::{limit::{lhopital, Limit}, terms, Polynomial};
+///
+/// // (x^2 - 1) / (x - 1) approaches 2 as x approaches 1
+/// let numerator: Polynomial<f64> = terms!(1., 2., -1., 0.);
+/// let denominator: Polynomial<f64> = terms!(1., 1., -1., 0.);
+///
+/// assert_eq!(lhopital(&numerator, &denominator, 1., 0.), Limit::Finite(2.));
+/// ```
+pub fn lhopital<T, N, D>(numerator: &N, denominator: &D, a: T, tolerance: T) -> Limit<T>
+where
+    T: Float + Pow<T, Output = T>,
+    N: Differentiation<T> + TermTrait<T> + Clone,
+    D: Differentiation<T> + TermTrait<T> + Clone,
+{
+    assert!(a.is_finite(), "lhopital can only be used at finite points");
+    let is_zero = |x: T| x.abs() <= tolerance;
+
+    let mut numerator = numerator.clone();
+    let mut denominator = denominator.clone();
+    for order in 0..MAX_ORDER {
+        let n = numerator.sum_with_respect_to(&a);
+        let d = denominator.sum_with_respect_to(&a);
+        if !is_zero(d) {
+            return Limit::Finite(n / d);
+        }
+        if !is_zero(n) {
+            // The denominator vanishes to a higher order than the
+            // numerator, so the ratio behaves like c / (x - a)^k
+            for k in 1..MAX_ORDER - order {
+                denominator = denominator.differentiate_self();
+                let d = denominator.sum_with_respect_to(&a);
+                if !is_zero(d) {
+                    return if k % 2 == 0 {
+                        Limit::infinite(n / d)
+                    } else {
+                        Limit::DoesNotExist
+                    };
+                }
+            }
+            return Limit::DoesNotExist;
+        }
+        numerator = numerator.differentiate_self();
+        denominator = denominator.differentiate_self();
+    }
+    Limit::DoesNotExist
+}
+
+impl<T> Polynomial<T>
+where
+    T: Float + Pow<T, Output = T>,
+{
+    /// Returns the limit of the polynomial as x approaches `a`,
+    /// which may be infinite. At infinity, the term with the largest
+    /// exponent decides the limit
+    /// ```
+    /// use cakcukus::{limit::Limit, terms, Polynomial};
+    ///
+    /// let p: Polynomial<f64> = terms!(-2., 3., 5., 2.); // -2x^3 + 5x^2
+    ///
+    /// assert_eq!(p.limit(f64::INFINITY), Limit::NegativeInfinity);
+    /// assert_eq!(p.limit(f64::NEG_INFINITY), Limit::PositiveInfinity);
+    /// assert_eq!(p.limit(1.), Limit::Finite(3.));
+    /// ```
+    pub fn limit(&self, a: T) -> Limit<T> {
+        let mut simplified = self.clone();
+        simplified.simplify();
+        simplified.0.retain(|t| !t.coefficient.is_zero());
+        if simplified.0.is_empty() {
+            return Limit::Finite(T::zero());
+        }
+
+        let has_pole = simplified.0.iter().any(|t| t.exponent < T::zero());
+        if a.is_infinite() || (a.is_zero() && has_pole) {
+            // Simplifying sorts the terms from largest to smallest
+            // exponent, so the dominant term is at one end
+            let dominant = if a.is_infinite() {
+                simplified.0[0]
+            } else {
+                simplified.0[simplified.0.len() - 1]
+            };
+            return power_limit(dominant.coefficient, dominant.exponent, a);
+        }
+
+        let mut total = T::zero();
+        for term in simplified.0.iter() {
+            total = total + term.coefficient * a.pow(term.exponent);
+        }
+        if total.is_finite() {
+            Limit::Finite(total)
+        } else {
+            Limit::DoesNotExist
+        }
+    }
+}
+
+/// Returns `polynomial` divided by the total size of its terms at
+/// `a`, so that the rounding error from evaluating it there is a few
+/// multiples of `T::epsilon()`, along with the size divided by
+fn normalised<T>(polynomial: &Polynomial<T>, a: T) -> (Polynomial<T>, T)
+where
+    T: Float + Pow<T, Output = T>,
+{
+    let size = polynomial
+        .0
+        .iter()
+        .map(|term| term.coefficient.abs() * a.abs().powf(term.exponent))
+        .filter(|size| size.is_finite())
+        .fold(T::zero(), |total, size| total + size);
+    let size = if size.is_normal() { size } else { T::one() };
+    let terms = polynomial
+        .0
+        .iter()
+        .map(|term| Term::new(term.coefficient / size, term.exponent))
+        .collect();
+    (Polynomial(terms), size)
+}
+
+impl<T> RationalFunction<T>
+where
+    T: Float + Pow<T, Output = T>,
+{
+    /// Returns the limit of the rational function as x approaches
+    /// `a`, which may be infinite. At infinity the ratio of the
+    /// leading terms decides the limit, and elsewhere
+    /// [lhopital](fn.lhopital.html) is used, with values within a few
+    /// rounding errors of 0 counting as 0
+    /// ```
+    /// use cakcukus::{limit::Limit, terms, RationalFunction};
+    ///
+    /// // (3x^2 + 1) / (x^2 - 4)
+    /// let f = RationalFunction::new(terms!(3., 2., 1., 0.), terms!(1., 2., -4., 0.));
+    ///
+    /// assert_eq!(f.limit(f64::INFINITY), Limit::Finite(3.));
+    /// // Different signs either side of the pole at x = 2
+    /// assert_eq!(f.limit(2.), Limit::DoesNotExist);
+    /// ```
+    pub fn limit(&self, a: T) -> Limit<T> {
+        let mut simplified = self.clone();
+        simplified.simplify();
+        simplified.numerator.0.retain(|t| !t.coefficient.is_zero());
+        simplified
+            .denominator
+            .0
+            .retain(|t| !t.coefficient.is_zero());
+
+        if simplified.denominator.0.is_empty() {
+            return Limit::DoesNotExist;
+        }
+        if simplified.numerator.0.is_empty() {
+            return Limit::Finite(T::zero());
+        }
+        if a.is_infinite() {
+            let n = simplified.numerator.0[0];
+            let d = simplified.denominator.0[0];
+            return power_limit(n.coefficient / d.coefficient, n.exponent - d.exponent, a);
+        }
+        // Rounding can leave a removable singularity slightly off 0,
+        // so after scaling both sides to a similar size, anything
+        // within a few rounding errors of 0 counts as 0
+        let (numerator, numerator_size) = normalised(&simplified.numerator, a);
+        let (denominator, denominator_size) = normalised(&simplified.denominator, a);
+        let tolerance = T::epsilon() * cast(64.);
+        match lhopital(&numerator, &denominator, a, tolerance) {
+            Limit::Finite(value) => Limit::Finite(value * numerator_size / denominator_size),
+            limit => limit,
+        }
+    }
+}
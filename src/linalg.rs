@@ -0,0 +1,226 @@
+//! Small dense linear algebra routines shared by the fitting and
+//! approximation code. Matrices are stored as a Vec of rows.
+
+use num_traits::Float;
+
+use crate::util::cast;
+
+/// Solves `matrix * x = rhs` by Gaussian elimination with partial
+/// pivoting, returning None if the matrix is singular, judged by a
+/// pivot smaller than `epsilon` times the largest entry
+pub(crate) fn solve<T: Float>(mut matrix: Vec<Vec<T>>, mut rhs: Vec<T>) -> Option<Vec<T>> {
+    let n = rhs.len();
+    let scale = matrix
+        .iter()
+        .flat_map(|row| row.iter())
+        .fold(T::zero(), |max, x| max.max(x.abs()));
+    let threshold = scale * T::epsilon() * cast::<T, _>(n.max(1) * 4);
+
+    for column in 0..n {
+        let pivot = (column..n).max_by(|a, b| {
+            matrix[*a][column]
+                .abs()
+                .partial_cmp(&matrix[*b][column].abs())
+                .unwrap_or(core::cmp::Ordering::Equal)
+        })?;
+        if matrix[pivot][column].abs() <= threshold {
+            return None;
+        }
+        matrix.swap(column, pivot);
+        rhs.swap(column, pivot);
+        let (above, below) = matrix.split_at_mut(column + 1);
+        let pivot_row = &above[column];
+        for (offset, row) in below.iter_mut().enumerate() {
+            let factor = row[column] / pivot_row[column];
+            for (value, pivot) in row.iter_mut().zip(pivot_row.iter()).skip(column) {
+                *value = *value - factor * *pivot;
+            }
+            rhs[column + 1 + offset] = rhs[column + 1 + offset] - factor * rhs[column];
+        }
+    }
+
+    let mut solution = vec![T::zero(); n];
+    for row in (0..n).rev() {
+        let mut total = rhs[row];
+        for k in row + 1..n {
+            total = total - matrix[row][k] * solution[k];
+        }
+        solution[row] = total / matrix[row][row];
+    }
+    Some(solution)
+}
+
+/// The least squares solution of an overdetermined system, along
+/// with the inverse of the R factor of its QR decomposition, from
+/// which the solution's covariance `(A^T A)^-1 = R^-1 R^-T` follows
+pub(crate) struct LeastSquares<T> {
+    pub(crate) solution: Vec<T>,
+    pub(crate) r_inverse: Vec<Vec<T>>,
+}
+
+/// Minimises `|matrix * x - rhs|` with Householder reflections, which
+/// avoids squaring the condition number as the normal equations do.
+/// Returns None if the columns of `matrix` aren't independent
+pub(crate) fn least_squares<T: Float>(
+    mut matrix: Vec<Vec<T>>,
+    mut rhs: Vec<T>,
+) -> Option<LeastSquares<T>> {
+    let rows = rhs.len();
+    let columns = matrix.first().map_or(0, |row| row.len());
+    if rows < columns {
+        return None;
+    }
+    let scale = matrix
+        .iter()
+        .flat_map(|row| row.iter())
+        .fold(T::zero(), |max, x| max.max(x.abs()));
+    let threshold = scale * T::epsilon() * cast::<T, _>(rows.max(1) * 4);
+
+    for k in 0..columns {
+        let norm = matrix[k..]
+            .iter()
+            .fold(T::zero(), |total, row| total.hypot(row[k]));
+        if norm <= threshold {
+            return None;
+        }
+        // Reflects the column onto -sign(a_kk) |a| e_k, so that no
+        // cancellation happens when forming v
+        let alpha = if matrix[k][k] > T::zero() {
+            -norm
+        } else {
+            norm
+        };
+        let mut v: Vec<T> = matrix[k..].iter().map(|row| row[k]).collect();
+        v[0] = v[0] - alpha;
+        let v_norm_squared = v.iter().fold(T::zero(), |total, x| total + *x * *x);
+
+        for j in k..columns {
+            let dot = v
+                .iter()
+                .zip(matrix[k..].iter())
+                .fold(T::zero(), |total, (vi, row)| total + *vi * row[j]);
+            let factor = (dot + dot) / v_norm_squared;
+            for (vi, row) in v.iter().zip(matrix[k..].iter_mut()) {
+                row[j] = row[j] - factor * *vi;
+            }
+        }
+        let dot = v
+            .iter()
+            .zip(rhs[k..].iter())
+            .fold(T::zero(), |total, (vi, b)| total + *vi * *b);
+        let factor = (dot + dot) / v_norm_squared;
+        for (vi, b) in v.iter().zip(rhs[k..].iter_mut()) {
+            *b = *b - factor * *vi;
+        }
+    }
+
+    // Back substitution against the upper triangular R, once for the
+    // solution and once per column of the identity for R^-1
+    let back_substitute = |target: &[T]| {
+        let mut x = vec![T::zero(); columns];
+        for row in (0..columns).rev() {
+            let mut total = target[row];
+            for k in row + 1..columns {
+                total = total - matrix[row][k] * x[k];
+            }
+            x[row] = total / matrix[row][row];
+        }
+        x
+    };
+    let solution = back_substitute(&rhs);
+    let mut r_inverse = vec![vec![T::zero(); columns]; columns];
+    for column in 0..columns {
+        let mut unit = vec![T::zero(); columns];
+        unit[column] = T::one();
+        for (row, value) in back_substitute(&unit).into_iter().enumerate() {
+            r_inverse[row][column] = value;
+        }
+    }
+    Some(LeastSquares {
+        solution,
+        r_inverse,
+    })
+}
+
+/// The most QL iterations spent on each eigenvalue before giving up
+const MAX_QL_ITERATIONS: usize = 64;
+
+/// Finds the eigenvalues of the symmetric tridiagonal matrix with
+/// the given diagonal, and `off_diagonal[i]` between rows i and i + 1,
+/// by the implicit QL method. Alongside each eigenvalue is the first
+/// component of its unit eigenvector, which is all Gauss quadrature
+/// needs. The pairs are sorted by eigenvalue, or None if any
+/// eigenvalue hasn't converged within `MAX_QL_ITERATIONS`
+pub(crate) fn tridiagonal_eigen<T: Float>(
+    diagonal: &[T],
+    off_diagonal: &[T],
+) -> Option<Vec<(T, T)>> {
+    let n = diagonal.len();
+    let mut d = diagonal.to_vec();
+    let mut e = off_diagonal.to_vec();
+    e.resize(n, T::zero());
+    // The first row of the accumulated rotations
+    let mut z = vec![T::zero(); n];
+    if n != 0 {
+        z[0] = T::one();
+    }
+    let two = T::one() + T::one();
+
+    for l in 0..n {
+        let mut converged = false;
+        for _ in 0..MAX_QL_ITERATIONS {
+            let mut m = l;
+            while m + 1 < n {
+                let scale = d[m].abs() + d[m + 1].abs();
+                if e[m].abs() <= T::epsilon() * scale {
+                    break;
+                }
+                m += 1;
+            }
+            if m == l {
+                converged = true;
+                break;
+            }
+            let mut g = (d[l + 1] - d[l]) / (two * e[l]);
+            let mut r = g.hypot(T::one());
+            g = d[m] - d[l] + e[l] / (g + if g < T::zero() { -r } else { r });
+            let (mut s, mut c, mut p) = (T::one(), T::one(), T::zero());
+            let mut underflow = false;
+            for i in (l..m).rev() {
+                let f = s * e[i];
+                let b = c * e[i];
+                r = f.hypot(g);
+                e[i + 1] = r;
+                if r.is_zero() {
+                    d[i + 1] = d[i + 1] - p;
+                    e[m] = T::zero();
+                    underflow = true;
+                    break;
+                }
+                s = f / r;
+                c = g / r;
+                g = d[i + 1] - p;
+                r = (d[i] - g) * s + two * c * b;
+                p = s * r;
+                d[i + 1] = g + p;
+                g = c * r - b;
+                let f = z[i + 1];
+                z[i + 1] = s * z[i] + c * f;
+                z[i] = c * z[i] - s * f;
+            }
+            if underflow {
+                continue;
+            }
+            d[l] = d[l] - p;
+            e[l] = g;
+            e[m] = T::zero();
+        }
+        if !converged {
+            return None;
+        }
+    }
+
+    let mut pairs: Vec<(T, T)> = d.into_iter().zip(z).collect();
+    pairs.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(core::cmp::Ordering::Equal));
+    Some(pairs)
+}
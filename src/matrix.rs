@@ -0,0 +1,203 @@
+//! The characteristic and minimal polynomials of square matrices,
+//! given as rows of `Vec<Vec<T>>`, or as nalgebra's `DMatrix` with
+//! the `nalgebra` feature.
+
+use num_traits::{identities::zero, Num, Pow};
+
+use crate::{util::from_u32, Polynomial};
+
+/// A square matrix the polynomials of this module can be found for
+pub trait SquareMatrix<T> {
+    /// Returns the rows of the matrix
+    ///
+    /// # Panics
+    /// If the matrix isn't square
+    fn rows(&self) -> Vec<Vec<T>>;
+}
+
+impl<T: Clone> SquareMatrix<T> for [Vec<T>] {
+    fn rows(&self) -> Vec<Vec<T>> {
+        assert!(
+            self.iter().all(|row| row.len() == self.len()),
+            "The matrix must be square"
+        );
+        self.to_vec()
+    }
+}
+
+impl<T: Clone> SquareMatrix<T> for Vec<Vec<T>> {
+    fn rows(&self) -> Vec<Vec<T>> {
+        self.as_slice().rows()
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl<T: nalgebra::Scalar> SquareMatrix<T> for nalgebra::DMatrix<T> {
+    fn rows(&self) -> Vec<Vec<T>> {
+        assert!(self.is_square(), "The matrix must be square");
+        self.row_iter()
+            .map(|row| row.iter().cloned().collect())
+            .collect()
+    }
+}
+
+/// Returns the product of two square matrices
+fn multiply<T: Num + Clone>(a: &[Vec<T>], b: &[Vec<T>]) -> Vec<Vec<T>> {
+    a.iter()
+        .map(|row| {
+            (0..b.len())
+                .map(|j| {
+                    row.iter()
+                        .zip(b.iter())
+                        .fold(zero(), |total: T, (x, b_row)| {
+                            total + x.clone() * b_row[j].clone()
+                        })
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Returns the product of a square matrix and a vector
+fn apply<T: Num + Clone>(matrix: &[Vec<T>], vector: &[T]) -> Vec<T> {
+    matrix
+        .iter()
+        .map(|row| {
+            row.iter()
+                .zip(vector.iter())
+                .fold(zero(), |total: T, (a, x)| total + a.clone() * x.clone())
+        })
+        .collect()
+}
+
+/// Returns the characteristic polynomial `det(xI - A)` of `matrix`,
+/// by the Faddeev–LeVerrier algorithm. This only divides by whole
+/// numbers, so is exact for [Rational](../exact/struct.Rational.html)
+/// matrices, though it can lose accuracy for large float ones
+///
+/// # Panics
+/// If the matrix isn't square
+/// ```
+/// use cakcukus::{matrix::characteristic_polynomial, terms, Polynomial};
+///
+/// let matrix = vec![vec![1., 2.], vec![3., 4.]];
+/// let expected: Polynomial<f64> = Polynomial(vec![
+///     terms!(1., 2.),
+///     terms!(-5., 1.),
+///     terms!(-2., 0.),
+/// ]);
+///
+/// assert_eq!(characteristic_polynomial(&matrix), expected);
+/// ```
+pub fn characteristic_polynomial<T, M>(matrix: &M) -> Polynomial<T>
+where
+    T: Num + Pow<T, Output = T> + Clone + PartialOrd,
+    M: SquareMatrix<T> + ?Sized,
+{
+    let a = matrix.rows();
+    let n = a.len();
+    // coefficients[k] belongs to x^(n - k)
+    let mut coefficients = vec![T::one()];
+    // M(k) = A M(k - 1) + c(k - 1) I, and c(k) = -tr(A M(k)) / k,
+    // starting from M(0) = 0
+    let mut product: Vec<Vec<T>> = vec![vec![zero(); n]; n];
+    for k in 1..=n {
+        let mut m = product;
+        for (i, row) in m.iter_mut().enumerate() {
+            row[i] = row[i].clone() + coefficients[k - 1].clone();
+        }
+        product = multiply(&a, &m);
+        let trace = (0..n).fold(zero::<T>(), |total, i| total + product[i][i].clone());
+        coefficients.push(zero::<T>() - trace / from_u32(k as u32));
+    }
+    coefficients.reverse();
+    Polynomial::from_coefficients(&coefficients)
+}
+
+/// Returns the monic polynomial p of least degree with `p(A)v = 0`,
+/// ascending, by finding the first power of A applied to v that
+/// depends on the ones before it
+fn local_minimal<T: Num + Clone>(matrix: &[Vec<T>], vector: Vec<T>) -> Vec<T> {
+    // Each reduced vector, with the index of its first non-zero
+    // entry, and its combination of the Krylov vectors v, Av, ...
+    let mut reduced: Vec<(usize, Vec<T>, Vec<T>)> = Vec::new();
+    let mut krylov = vector;
+    for k in 0..=matrix.len() {
+        let mut remainder = krylov.clone();
+        let mut combination = vec![zero(); k + 1];
+        combination[k] = T::one();
+        for (pivot, basis, basis_combination) in reduced.iter() {
+            let factor = remainder[*pivot].clone() / basis[*pivot].clone();
+            if factor.is_zero() {
+                continue;
+            }
+            for (r, b) in remainder.iter_mut().zip(basis.iter()) {
+                *r = r.clone() - factor.clone() * b.clone();
+            }
+            for (c, b) in combination.iter_mut().zip(basis_combination.iter()) {
+                *c = c.clone() - factor.clone() * b.clone();
+            }
+        }
+        match remainder.iter().position(|x| !x.is_zero()) {
+            Some(pivot) => reduced.push((pivot, remainder, combination)),
+            None => return combination,
+        }
+        krylov = apply(matrix, &krylov);
+    }
+    unreachable!("Any n + 1 vectors of length n are linearly dependent")
+}
+
+/// Returns the minimal polynomial of `matrix`, the monic polynomial
+/// of least degree with `p(A) = 0`, which divides the characteristic
+/// polynomial. Each basis vector's own minimal polynomial is found
+/// from its Krylov sequence `e, Ae, A^2e, ...`, and multiplied in
+/// after removing the factors already found, giving their least
+/// common multiple. This relies on exact tests for 0, so is meant
+/// for exact types such as [Rational](../exact/struct.Rational.html)
+///
+/// # Panics
+/// If the matrix isn't square
+/// ```
+/// use cakcukus::{exact::Rational, matrix::minimal_polynomial, terms};
+///
+/// let r = Rational::from_integer;
+/// // 2I has the characteristic polynomial (x - 2)^3
+/// let matrix = vec![
+///     vec![r(2), r(0), r(0)],
+///     vec![r(0), r(2), r(0)],
+///     vec![r(0), r(0), r(2)],
+/// ];
+///
+/// assert_eq!(minimal_polynomial(&matrix), terms!(r(1), r(1), r(-2), r(0)));
+/// ```
+pub fn minimal_polynomial<T, M>(matrix: &M) -> Polynomial<T>
+where
+    T: Num + Pow<T, Output = T> + Clone + PartialOrd,
+    M: SquareMatrix<T> + ?Sized,
+{
+    let a = matrix.rows();
+    let n = a.len();
+    // Ascending coefficients of the product so far
+    let mut minimal = vec![T::one()];
+    for column in 0..n {
+        // The minimal polynomial of m(A)e is that of e, without the
+        // factors it shares with m. m(A)e is found by Horner's method
+        let mut w: Vec<T> = vec![zero(); n];
+        for c in minimal.iter().rev() {
+            w = apply(&a, &w);
+            w[column] = w[column].clone() + c.clone();
+        }
+        if w.iter().all(|x| x.is_zero()) {
+            continue;
+        }
+        let factor = local_minimal(&a, w);
+        let mut product = vec![zero::<T>(); minimal.len() + factor.len() - 1];
+        for (i, x) in minimal.iter().enumerate() {
+            for (j, y) in factor.iter().enumerate() {
+                product[i + j] = product[i + j].clone() + x.clone() * y.clone();
+            }
+        }
+        minimal = product;
+    }
+    Polynomial::from_coefficients(&minimal)
+}
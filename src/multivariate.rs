@@ -0,0 +1,473 @@
+use core::cmp::Ordering;
+
+use num_traits::{identities::zero, pow, Num};
+
+use crate::util::from_u32;
+
+/// Removes any trailing zero exponents, so that equal monomials
+/// always have equal exponent vectors
+fn trim(exponents: &mut Vec<u32>) {
+    while exponents.last() == Some(&0) {
+        exponents.pop();
+    }
+}
+
+/// An ordering of monomials, used to decide which term of a
+/// [MultiPolynomial](struct.MultiPolynomial.html) leads
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MonomialOrder {
+    /// Compares exponents variable by variable, starting with x0
+    Lex,
+    /// Compares total degree, then breaks ties with `Lex`
+    #[default]
+    GradedLex,
+    /// Compares total degree, then the monomial with the smallest
+    /// exponent in the last differing variable is larger
+    GradedReverseLex,
+    /// Compares the total degree of the first `k` variables, then
+    /// breaks ties with `GradedReverseLex`. A Gröbner basis in this
+    /// order contains a basis of the ideal with those variables
+    /// eliminated
+    Elimination(usize),
+}
+
+impl MonomialOrder {
+    /// Compares the monomials with the exponents `a` and `b`,
+    /// missing exponents being treated as 0
+    pub fn compare(&self, a: &[u32], b: &[u32]) -> Ordering {
+        let len = a.len().max(b.len());
+        let exponent = |e: &[u32], i: usize| e.get(i).copied().unwrap_or(0);
+        let lex = || {
+            (0..len)
+                .map(|i| exponent(a, i).cmp(&exponent(b, i)))
+                .find(|o| *o != Ordering::Equal)
+                .unwrap_or(Ordering::Equal)
+        };
+        let degree = |e: &[u32]| e.iter().sum::<u32>();
+        let reverse_lex = || {
+            (0..len)
+                .rev()
+                .map(|i| exponent(b, i).cmp(&exponent(a, i)))
+                .find(|o| *o != Ordering::Equal)
+                .unwrap_or(Ordering::Equal)
+        };
+        match self {
+            MonomialOrder::Lex => lex(),
+            MonomialOrder::GradedLex => degree(a).cmp(&degree(b)).then_with(lex),
+            MonomialOrder::GradedReverseLex => degree(a).cmp(&degree(b)).then_with(reverse_lex),
+            MonomialOrder::Elimination(k) => {
+                let block = |e: &[u32]| e.iter().take(*k).sum::<u32>();
+                block(a)
+                    .cmp(&block(b))
+                    .then_with(|| degree(a).cmp(&degree(b)))
+                    .then_with(reverse_lex)
+            }
+        }
+    }
+}
+
+/// A MultiTerm is used to represent a single term in an equation
+/// of several variables, being `coefficient * x0^e0 * x1^e1 * ...`.
+///
+/// Unlike [Term](../term/struct.Term.html), the exponents are
+/// natural numbers, which keeps the usual monomial orderings well
+/// defined
+#[derive(Debug, Clone, PartialEq)]
+pub struct MultiTerm<T>
+where
+    T: Num + Clone,
+{
+    /// The number that the variables are multiplied by in the term
+    pub coefficient: T,
+
+    /// The power each variable is raised by, with the nth exponent
+    /// belonging to the nth variable
+    pub exponents: Vec<u32>,
+}
+
+impl<T: Num + Clone> MultiTerm<T> {
+    /// Constructs a new term based off a given coefficient and
+    /// exponents
+    pub fn new(coefficient: T, mut exponents: Vec<u32>) -> Self {
+        trim(&mut exponents);
+        Self {
+            coefficient,
+            exponents,
+        }
+    }
+    /// Returns the exponent of the variable `var`
+    pub fn exponent(&self, var: usize) -> u32 {
+        self.exponents.get(var).copied().unwrap_or(0)
+    }
+    /// The sum of every exponent in the term
+    pub fn total_degree(&self) -> u32 {
+        self.exponents.iter().sum()
+    }
+    /// Evaluates the term, with the nth variable taking the
+    /// nth value of `point`
+    ///
+    /// # Panics
+    /// If the term uses a variable `point` has no value for
+    pub fn evaluate(&self, point: &[T]) -> T {
+        let mut total = self.coefficient.clone();
+        for (var, exponent) in self.exponents.iter().enumerate() {
+            if *exponent != 0 {
+                total = total * pow(point[var].clone(), *exponent as usize);
+            }
+        }
+        total
+    }
+    /// Returns the partial derivative of the term with
+    /// respect to the variable `var`
+    pub fn partial_derivative(&self, var: usize) -> Self {
+        let exponent = self.exponent(var);
+        if exponent == 0 {
+            return Self::new(zero(), Vec::new());
+        }
+        let mut exponents = self.exponents.clone();
+        exponents[var] -= 1;
+        Self::new(self.coefficient.clone() * from_u32(exponent), exponents)
+    }
+    /// Intergrates the term with respect to the variable `var`
+    pub fn integrate_with_respect_to(&self, var: usize) -> Self {
+        let mut exponents = self.exponents.clone();
+        if exponents.len() <= var {
+            exponents.resize(var + 1, 0);
+        }
+        exponents[var] += 1;
+        let divisor = from_u32(exponents[var]);
+        Self::new(self.coefficient.clone() / divisor, exponents)
+    }
+    /// Replaces the variable `var` with `value`
+    pub fn substitute(&self, var: usize, value: T) -> Self {
+        let exponent = self.exponent(var);
+        if exponent == 0 {
+            return self.clone();
+        }
+        let mut exponents = self.exponents.clone();
+        exponents[var] = 0;
+        Self::new(
+            self.coefficient.clone() * pow(value, exponent as usize),
+            exponents,
+        )
+    }
+}
+
+/// A sum of [MultiTerm](struct.MultiTerm.html)s, being a
+/// polynomial in several variables
+#[derive(Debug, Clone, PartialEq)]
+pub struct MultiPolynomial<T>(pub Vec<MultiTerm<T>>)
+where
+    T: Num + Clone;
+
+impl<T: Num + Clone> MultiPolynomial<T> {
+    pub fn new() -> Self {
+        Self(Vec::new())
+    }
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self(Vec::with_capacity(capacity))
+    }
+    /// The largest total degree of any term
+    pub fn total_degree(&self) -> u32 {
+        self.0
+            .iter()
+            .map(MultiTerm::total_degree)
+            .max()
+            .unwrap_or(0)
+    }
+    /// The number of variables used, being one more than the
+    /// largest variable index
+    pub fn variables(&self) -> usize {
+        self.0.iter().map(|t| t.exponents.len()).max().unwrap_or(0)
+    }
+    /// Evaluates the polynomial, with the nth variable taking the
+    /// nth value of `point`
+    pub fn evaluate(&self, point: &[T]) -> T {
+        let mut total = zero();
+        for term in self.0.iter() {
+            total = total + term.evaluate(point);
+        }
+        total
+    }
+    /// Returns the partial derivative of the polynomial with
+    /// respect to the variable `var`
+    pub fn partial_derivative(&self, var: usize) -> Self {
+        Self(
+            self.0
+                .iter()
+                .filter(|t| t.exponent(var) != 0)
+                .map(|t| t.partial_derivative(var))
+                .collect(),
+        )
+    }
+    /// Intergrates the polynomial with respect to the variable `var`,
+    /// taking the constant of integration to be 0
+    pub fn integrate_with_respect_to(&self, var: usize) -> Self {
+        Self(
+            self.0
+                .iter()
+                .map(|t| t.integrate_with_respect_to(var))
+                .collect(),
+        )
+    }
+    /// Intergrates the polynomial with respect to the variable `var`
+    /// between `lower` and `upper`, leaving a polynomial in the
+    /// other variables
+    /// ```
+    /// use cakcukus::{MultiPolynomial, MultiTerm};
+    ///
+    /// // 6xy^2
+    /// let p = MultiPolynomial(vec![MultiTerm::new(6., vec![1, 2])]);
+    ///
+    /// // Intergrating y from 0 to 1 leaves 2x
+    /// let mut integrated = p.integrate_between(1, 0., 1.);
+    /// integrated.simplify();
+    ///
+    /// assert_eq!(integrated, MultiPolynomial(vec![MultiTerm::new(2., vec![1])]));
+    /// ```
+    pub fn integrate_between(&self, var: usize, lower: T, upper: T) -> Self {
+        let integrated = self.integrate_with_respect_to(var);
+        let mut result = integrated.substitute(var, upper);
+        for term in integrated.0.iter() {
+            let mut term = term.substitute(var, lower.clone());
+            term.coefficient = zero::<T>() - term.coefficient;
+            result.0.push(term);
+        }
+        result
+    }
+    /// Replaces the variable `var` with `value` in every term
+    pub fn substitute(&self, var: usize, value: T) -> Self {
+        Self(
+            self.0
+                .iter()
+                .map(|t| t.substitute(var, value.clone()))
+                .collect(),
+        )
+    }
+    /// Sorts the terms from largest to smallest by `order`
+    pub fn sort_by_order(&mut self, order: MonomialOrder) {
+        self.0
+            .sort_by(|a, b| order.compare(&b.exponents, &a.exponents));
+    }
+    /// Returns the largest term by `order`, if there is one
+    pub fn leading_term(&self, order: MonomialOrder) -> Option<&MultiTerm<T>> {
+        self.0
+            .iter()
+            .max_by(|a, b| order.compare(&a.exponents, &b.exponents))
+    }
+    /// Combines like terms, removes any zero terms, and sorts
+    /// the remaining terms from largest to smallest by `order`
+    pub fn simplify_by(&mut self, order: MonomialOrder) {
+        self.sort_by_order(order);
+        let mut simplified: Vec<MultiTerm<T>> = Vec::with_capacity(self.0.len());
+        for mut term in self.0.drain(..) {
+            trim(&mut term.exponents);
+            match simplified.last_mut() {
+                Some(last) if last.exponents == term.exponents => {
+                    last.coefficient = last.coefficient.clone() + term.coefficient;
+                }
+                _ => simplified.push(term),
+            }
+        }
+        simplified.retain(|t| !t.coefficient.is_zero());
+        self.0 = simplified;
+    }
+    /// Combines like terms, removes any zero terms, and sorts
+    /// the remaining terms by the default `MonomialOrder`
+    pub fn simplify(&mut self) {
+        self.simplify_by(MonomialOrder::default())
+    }
+}
+
+impl<T: Num + Clone> MultiPolynomial<T> {
+    /// Returns every first order partial derivative, with the nth
+    /// being with respect to the nth variable
+    pub fn gradient(&self) -> Vec<Self> {
+        (0..self.variables())
+            .map(|var| self.partial_derivative(var))
+            .collect()
+    }
+    /// Evaluates the gradient at `point`, which also decides how
+    /// many variables there are
+    pub fn gradient_at(&self, point: &[T]) -> Vec<T> {
+        (0..point.len())
+            .map(|var| self.partial_derivative(var).evaluate(point))
+            .collect()
+    }
+    /// Returns the matrix of second order partial derivatives,
+    /// where the ith row and jth column is differentiated with
+    /// respect to the ith then the jth variable
+    pub fn hessian(&self) -> Vec<Vec<Self>> {
+        hessian_of(self, self.variables())
+    }
+    /// Evaluates the hessian at `point`, which also decides how
+    /// many variables there are
+    /// ```
+    /// use cakcukus::{MultiPolynomial, MultiTerm};
+    ///
+    /// // x^2y + y^3
+    /// let p = MultiPolynomial(vec![
+    ///     MultiTerm::new(1., vec![2, 1]),
+    ///     MultiTerm::new(1., vec![0, 3]),
+    /// ]);
+    ///
+    /// // [[2y, 2x], [2x, 6y]]
+    /// assert_eq!(p.hessian_at(&[1., 2.]), vec![vec![4., 2.], vec![2., 12.]]);
+    /// ```
+    pub fn hessian_at(&self, point: &[T]) -> Vec<Vec<T>> {
+        hessian_of(self, point.len())
+            .iter()
+            .map(|row| row.iter().map(|d| d.evaluate(point)).collect())
+            .collect()
+    }
+}
+
+fn hessian_of<T: Num + Clone>(
+    polynomial: &MultiPolynomial<T>,
+    variables: usize,
+) -> Vec<Vec<MultiPolynomial<T>>> {
+    let mut hessian: Vec<Vec<MultiPolynomial<T>>> = Vec::with_capacity(variables);
+    for i in 0..variables {
+        let first = polynomial.partial_derivative(i);
+        // The matrix is symmetric, so reuse the entries above
+        // the diagonal
+        let mut row: Vec<MultiPolynomial<T>> = hessian.iter().map(|r| r[i].clone()).collect();
+        row.extend((i..variables).map(|j| first.partial_derivative(j)));
+        hessian.push(row);
+    }
+    hessian
+}
+
+/// Returns the jacobian of `functions`, where the ith row holds
+/// the gradient of the ith function
+pub fn jacobian<T: Num + Clone>(functions: &[MultiPolynomial<T>]) -> Vec<Vec<MultiPolynomial<T>>> {
+    let variables = functions
+        .iter()
+        .map(MultiPolynomial::variables)
+        .max()
+        .unwrap_or(0);
+    functions
+        .iter()
+        .map(|f| {
+            (0..variables)
+                .map(|var| f.partial_derivative(var))
+                .collect()
+        })
+        .collect()
+}
+
+/// Evaluates the jacobian of `functions` at `point`, which also
+/// decides how many variables there are
+pub fn jacobian_at<T: Num + Clone>(functions: &[MultiPolynomial<T>], point: &[T]) -> Vec<Vec<T>> {
+    functions.iter().map(|f| f.gradient_at(point)).collect()
+}
+
+impl<T: Num + Clone> Default for MultiPolynomial<T> {
+    fn default() -> Self {
+        Self(Vec::new())
+    }
+}
+
+mod impl_std_traits {
+    use super::{MultiPolynomial, MultiTerm};
+    use core::{
+        fmt::Display,
+        ops::{Add, Mul, Neg, Sub},
+    };
+
+    use num_traits::Num;
+
+    impl<T: Num + Clone> Mul for MultiTerm<T> {
+        type Output = Self;
+
+        #[allow(clippy::suspicious_arithmetic_impl)]
+        fn mul(self, rhs: Self) -> Self::Output {
+            let len = self.exponents.len().max(rhs.exponents.len());
+            let exponents = (0..len)
+                .map(|i| self.exponent(i) + rhs.exponent(i))
+                .collect();
+            Self::new(self.coefficient * rhs.coefficient, exponents)
+        }
+    }
+    impl<T: Num + Clone + Neg<Output = T>> Neg for MultiTerm<T> {
+        type Output = Self;
+
+        fn neg(self) -> Self::Output {
+            Self::new(-self.coefficient, self.exponents)
+        }
+    }
+
+    impl<T: Num + Clone> Add for MultiPolynomial<T> {
+        type Output = Self;
+
+        fn add(mut self, rhs: Self) -> Self::Output {
+            self.0.extend(rhs.0);
+            self.simplify();
+            self
+        }
+    }
+    impl<T: Num + Clone> Sub for MultiPolynomial<T> {
+        type Output = Self;
+
+        fn sub(mut self, rhs: Self) -> Self::Output {
+            for mut term in rhs.0 {
+                term.coefficient = T::zero() - term.coefficient;
+                self.0.push(term);
+            }
+            self.simplify();
+            self
+        }
+    }
+    impl<T: Num + Clone> Mul for MultiPolynomial<T> {
+        type Output = Self;
+
+        fn mul(self, rhs: Self) -> Self::Output {
+            let mut to_return = Vec::with_capacity(self.0.len() * rhs.0.len());
+            for term in self.0.iter() {
+                for rhs_term in rhs.0.iter() {
+                    to_return.push(term.clone() * rhs_term.clone());
+                }
+            }
+            let mut to_return = Self(to_return);
+            to_return.simplify();
+            to_return
+        }
+    }
+    impl<T: Num + Clone> Mul<MultiTerm<T>> for MultiPolynomial<T> {
+        type Output = Self;
+
+        fn mul(self, rhs: MultiTerm<T>) -> Self::Output {
+            Self(self.0.into_iter().map(|t| t * rhs.clone()).collect())
+        }
+    }
+    impl<T: Num + Clone + Neg<Output = T>> Neg for MultiPolynomial<T> {
+        type Output = Self;
+
+        fn neg(self) -> Self::Output {
+            Self(self.0.into_iter().map(|t| -t).collect())
+        }
+    }
+
+    impl<T: Num + Clone + Display> Display for MultiTerm<T> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{}", self.coefficient)?;
+            for (var, exponent) in self.exponents.iter().enumerate() {
+                if *exponent != 0 {
+                    write!(f, "x{}^{}", var, exponent)?;
+                }
+            }
+            Ok(())
+        }
+    }
+    impl<T: Num + Clone + Display> Display for MultiPolynomial<T> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            for (i, term) in self.0.iter().enumerate() {
+                if i != 0 {
+                    write!(f, " + ")?;
+                }
+                write!(f, "{}", term)?;
+            }
+            Ok(())
+        }
+    }
+}
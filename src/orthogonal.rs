@@ -0,0 +1,278 @@
+//! The classical orthogonal polynomials, built from their three term
+//! recurrences, along with the Gauss quadrature rules they give rise
+//! to, and conversion between the monomial and Chebyshev bases.
+
+use num_traits::{Float, Pow};
+
+use crate::{linalg, util::cast, Polynomial};
+
+/// Returns the gamma function at `x > 0`, from Lanczos' approximation
+fn gamma<T: Float>(x: T) -> T {
+    const G: f64 = 7.;
+    const COEFFICIENTS: [f64; 9] = [
+        0.999_999_999_999_809_9,
+        676.520_368_121_885_1,
+        -1_259.139_216_722_402_8,
+        771.323_428_777_653_1,
+        -176.615_029_162_140_6,
+        12.507_343_278_686_905,
+        -0.138_571_095_265_720_12,
+        9.984_369_578_019_572e-6,
+        1.505_632_735_149_311_6e-7,
+    ];
+    let half = cast::<T, _>(0.5);
+    if x < half {
+        // The reflection formula, gamma(x) gamma(1 - x) = pi / sin(pi x)
+        let pi = cast::<T, _>(core::f64::consts::PI);
+        return pi / ((pi * x).sin() * gamma(T::one() - x));
+    }
+    let x = x - T::one();
+    let mut sum = cast::<T, _>(COEFFICIENTS[0]);
+    for (i, c) in COEFFICIENTS.iter().enumerate().skip(1) {
+        sum = sum + cast::<T, _>(*c) / (x + cast(i));
+    }
+    let t = x + cast::<T, _>(G) + half;
+    let tau = cast::<T, _>(2. * core::f64::consts::PI);
+    tau.sqrt() * t.powf(x + half) * (-t).exp() * sum
+}
+
+/// A family of orthogonal polynomials, each satisfying
+/// `p(n + 1) = (a x + b) p(n) - c p(n - 1)`, with `p(0) = 1`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Family<T> {
+    /// Chebyshev polynomials of the first kind, `T(n)(cos t) = cos(nt)`,
+    /// orthogonal on [-1, 1] with weight `1 / sqrt(1 - x^2)`
+    ChebyshevFirst,
+    /// Chebyshev polynomials of the second kind,
+    /// `U(n)(cos t) sin t = sin((n + 1)t)`, orthogonal on [-1, 1] with
+    /// weight `sqrt(1 - x^2)`
+    ChebyshevSecond,
+    /// Legendre polynomials, orthogonal on [-1, 1] with weight 1
+    Legendre,
+    /// The physicists' Hermite polynomials, orthogonal on the real
+    /// line with weight `e^(-x^2)`
+    Hermite,
+    /// The generalised Laguerre polynomials, orthogonal on [0, inf)
+    /// with weight `x^alpha e^(-x)`, where `alpha > -1`
+    Laguerre { alpha: T },
+    /// Jacobi polynomials, orthogonal on [-1, 1] with weight
+    /// `(1 - x)^alpha (1 + x)^beta`, where `alpha, beta > -1`
+    Jacobi { alpha: T, beta: T },
+}
+
+impl<T> Family<T>
+where
+    T: Float + Pow<T, Output = T>,
+{
+    /// Returns `(a, b, c)` in the recurrence for `p(n + 1)`
+    pub fn recurrence(&self, n: usize) -> (T, T, T) {
+        let one = T::one();
+        let two = one + one;
+        let k: T = cast(n);
+        match *self {
+            Family::ChebyshevFirst if n == 0 => (one, T::zero(), T::zero()),
+            Family::ChebyshevFirst | Family::ChebyshevSecond => (two, T::zero(), one),
+            Family::Legendre => ((two * k + one) / (k + one), T::zero(), k / (k + one)),
+            Family::Hermite => (two, T::zero(), two * k),
+            Family::Laguerre { alpha } => (
+                -one / (k + one),
+                (two * k + one + alpha) / (k + one),
+                (k + alpha) / (k + one),
+            ),
+            Family::Jacobi { alpha, beta } if n == 0 => {
+                ((alpha + beta + two) / two, (alpha - beta) / two, T::zero())
+            }
+            Family::Jacobi { alpha, beta } => {
+                let s = two * k + alpha + beta;
+                let d = two * (k + one) * (k + alpha + beta + one) * s;
+                (
+                    (s + one) * (s + two) * s / d,
+                    (s + one) * (alpha * alpha - beta * beta) / d,
+                    two * (k + alpha) * (k + beta) * (s + two) / d,
+                )
+            }
+        }
+    }
+    /// Returns the weight function the family is orthogonal under
+    pub fn weight(&self, x: T) -> T {
+        let one = T::one();
+        match *self {
+            Family::ChebyshevFirst => one / (one - x * x).sqrt(),
+            Family::ChebyshevSecond => (one - x * x).sqrt(),
+            Family::Legendre => one,
+            Family::Hermite => (-x * x).exp(),
+            Family::Laguerre { alpha } => x.powf(alpha) * (-x).exp(),
+            Family::Jacobi { alpha, beta } => (one - x).powf(alpha) * (one + x).powf(beta),
+        }
+    }
+    /// Returns the integral of the weight function over the family's
+    /// interval
+    fn total_weight(&self) -> T {
+        let one = T::one();
+        let two = one + one;
+        let pi = cast::<T, _>(core::f64::consts::PI);
+        match *self {
+            Family::ChebyshevFirst => pi,
+            Family::ChebyshevSecond => pi / two,
+            Family::Legendre => two,
+            Family::Hermite => pi.sqrt(),
+            Family::Laguerre { alpha } => gamma(alpha + one),
+            Family::Jacobi { alpha, beta } => {
+                two.powf(alpha + beta + one) * gamma(alpha + one) * gamma(beta + one)
+                    / gamma(alpha + beta + two)
+            }
+        }
+    }
+    /// Returns the nth polynomial of the family
+    /// ```
+    /// use cakcukus::{orthogonal::Family, terms, Polynomial};
+    ///
+    /// // T3 = 4x^3 - 3x
+    /// let expected: Polynomial<f64> = terms!(4., 3., -3., 1.);
+    ///
+    /// assert_eq!(Family::ChebyshevFirst.polynomial(3), expected);
+    /// ```
+    pub fn polynomial(&self, n: usize) -> Polynomial<T> {
+        // Coefficients, lowest power first
+        let mut previous: Vec<T> = Vec::new();
+        let mut current = vec![T::one()];
+        for k in 0..n {
+            let (a, b, c) = self.recurrence(k);
+            let mut next = vec![T::zero(); current.len() + 1];
+            for (power, coefficient) in current.iter().enumerate() {
+                next[power + 1] = next[power + 1] + a * *coefficient;
+                next[power] = next[power] + b * *coefficient;
+            }
+            for (power, coefficient) in previous.iter().enumerate() {
+                next[power] = next[power] - c * *coefficient;
+            }
+            previous = current;
+            current = next;
+        }
+        Polynomial::from_coefficients(&current)
+    }
+    /// Evaluates the nth polynomial of the family at `x` directly
+    /// from the recurrence, without building the polynomial
+    pub fn evaluate(&self, n: usize, x: T) -> T {
+        let mut previous = T::zero();
+        let mut current = T::one();
+        for k in 0..n {
+            let (a, b, c) = self.recurrence(k);
+            let next = (a * x + b) * current - c * previous;
+            previous = current;
+            current = next;
+        }
+        current
+    }
+    /// Returns the nodes and weights of the `order` point Gauss rule
+    /// for the family's weight function, as `(node, weight)` pairs
+    /// sorted by node. The nodes are the eigenvalues of the
+    /// recurrence's Jacobi matrix, by the Golub–Welsch algorithm
+    ///
+    /// # Panics
+    /// If the eigenvalues don't converge, as happens when `alpha` or
+    /// `beta` isn't above -1
+    pub fn gauss_nodes(&self, order: usize) -> Vec<(T, T)> {
+        // The monic recurrence is p(n + 1) = (x - d(n)) p(n) - e(n) p(n - 1)
+        let mut diagonal = Vec::with_capacity(order);
+        let mut off_diagonal = Vec::with_capacity(order);
+        let mut previous_a = T::one();
+        for n in 0..order {
+            let (a, b, c) = self.recurrence(n);
+            diagonal.push(-b / a);
+            if n != 0 {
+                off_diagonal.push((c / (a * previous_a)).sqrt());
+            }
+            previous_a = a;
+        }
+        let total = self.total_weight();
+        linalg::tridiagonal_eigen(&diagonal, &off_diagonal)
+            .expect("The Gauss nodes should have converged")
+            .into_iter()
+            .map(|(node, first)| (node, total * first * first))
+            .collect()
+    }
+    /// Approximates the integral of `weight(x) f(x)` over the family's
+    /// interval with the `order` point Gauss rule, which is exact
+    /// whenever `f` is a polynomial of degree below `2 * order`
+    ///
+    /// # Panics
+    /// In the same cases as [gauss_nodes](#method.gauss_nodes)
+    /// ```
+    /// use cakcukus::orthogonal::Family;
+    ///
+    /// // The integral of x^2 e^(-x^2) over the real line is sqrt(pi) / 2
+    /// let integral = Family::Hermite.integrate(|x: f64| x * x, 2);
+    ///
+    /// assert!((integral - std::f64::consts::PI.sqrt() / 2.).abs() < 1e-12);
+    /// ```
+    pub fn integrate<F: Fn(T) -> T>(&self, f: F, order: usize) -> T {
+        self.gauss_nodes(order)
+            .into_iter()
+            .fold(T::zero(), |total, (node, weight)| total + weight * f(node))
+    }
+}
+
+impl<T> Polynomial<T>
+where
+    T: Float + Pow<T, Output = T>,
+{
+    /// Builds the polynomial `c0 T0(x) + c1 T1(x) + ...` from its
+    /// coefficients in the Chebyshev basis
+    pub fn from_chebyshev(coefficients: &[T]) -> Self {
+        let mut total = vec![T::zero(); coefficients.len()];
+        let (mut previous, mut current): (Vec<T>, Vec<T>) = (Vec::new(), vec![T::one()]);
+        for (n, coefficient) in coefficients.iter().enumerate() {
+            for (sum, c) in total.iter_mut().zip(current.iter()) {
+                *sum = *sum + *coefficient * *c;
+            }
+            let (a, _, c) = Family::<T>::ChebyshevFirst.recurrence(n);
+            let mut next = vec![T::zero(); current.len() + 1];
+            for (power, value) in current.iter().enumerate() {
+                next[power + 1] = a * *value;
+            }
+            for (power, value) in previous.iter().enumerate() {
+                next[power] = next[power] - c * *value;
+            }
+            previous = current;
+            current = next;
+        }
+        Self::from_coefficients(&total)
+    }
+    /// Returns the coefficients of the polynomial in the Chebyshev
+    /// basis, `c0 T0(x) + c1 T1(x) + ...`
+    ///
+    /// # Panics
+    /// If any exponent isn't a whole number of at least 0
+    /// ```
+    /// use cakcukus::{terms, Polynomial};
+    ///
+    /// // x^2 = (T0 + T2) / 2
+    /// let square: Polynomial<f64> = Polynomial(vec![terms!(1., 2.)]);
+    ///
+    /// assert_eq!(square.to_chebyshev(), vec![0.5, 0., 0.5]);
+    /// ```
+    pub fn to_chebyshev(&self) -> Vec<T> {
+        let monomial = self
+            .dense_coefficients()
+            .expect("Only whole, non-negative exponents have a Chebyshev form");
+        let half = cast::<T, _>(0.5);
+        // Horner's method, using x T0 = T1 and
+        // x Tk = (Tk-1 + Tk+1) / 2
+        let mut result: Vec<T> = Vec::with_capacity(monomial.len());
+        for coefficient in monomial.iter().rev() {
+            let mut shifted = vec![T::zero(); result.len() + 1];
+            for (k, value) in result.iter().enumerate() {
+                if k == 0 {
+                    shifted[1] = shifted[1] + *value;
+                } else {
+                    shifted[k - 1] = shifted[k - 1] + half * *value;
+                    shifted[k + 1] = shifted[k + 1] + half * *value;
+                }
+            }
+            shifted[0] = shifted[0] + *coefficient;
+            result = shifted;
+        }
+        result
+    }
+}
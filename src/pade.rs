@@ -0,0 +1,98 @@
+//! Padé approximants, the [RationalFunction](../struct.RationalFunction.html)
+//! `P(x) / Q(x)` whose series agrees with a given series as far as
+//! possible. They usually converge much further from 0 than the
+//! series' own partial sums.
+
+use core::fmt::Display;
+
+use num_traits::{Float, Pow};
+
+use crate::{linalg, Polynomial, PowerSeries, RationalFunction};
+
+/// The reasons a Padé approximant couldn't be built
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PadeError {
+    /// An `[m/n]` approximant needs the first `m + n + 1` coefficients
+    InsufficientCoefficients,
+    /// The coefficients don't determine a unique denominator, as
+    /// happens in the blocks of repeated entries in the Padé table,
+    /// such as for `[1/1]` of an even function
+    Singular,
+}
+
+impl Display for PadeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PadeError::InsufficientCoefficients => {
+                write!(f, "not enough coefficients for the approximant")
+            }
+            PadeError::Singular => write!(f, "the approximant is degenerate"),
+        }
+    }
+}
+
+impl std::error::Error for PadeError {}
+
+/// Builds the `[m/n]` Padé approximant from the series coefficients
+/// `c0 + c1x + c2x^2 + ...`, with a numerator of degree at most `m`,
+/// and a denominator of degree at most `n` with a constant term of 1
+/// ```
+/// use cakcukus::{pade::pade, traits::TermTrait};
+///
+/// // e^x = 1 + x + x^2 / 2 + x^3 / 6 + x^4 / 24 + ...
+/// let exp = pade(&[1., 1., 0.5, 1. / 6., 1. / 24.], 2, 2).unwrap();
+///
+/// assert!((exp.sum_with_respect_to(&1.) - 1f64.exp()).abs() < 5e-3);
+/// ```
+pub fn pade<T>(coefficients: &[T], m: usize, n: usize) -> Result<RationalFunction<T>, PadeError>
+where
+    T: Float + Pow<T, Output = T>,
+{
+    if coefficients.len() < m + n + 1 {
+        return Err(PadeError::InsufficientCoefficients);
+    }
+    let c = |i: isize| {
+        if i < 0 {
+            T::zero()
+        } else {
+            coefficients[i as usize]
+        }
+    };
+
+    // The denominator q1..qn cancels the series' terms from x^(m+1)
+    // up to x^(m+n), giving a Toeplitz system
+    let mut matrix = Vec::with_capacity(n);
+    let mut rhs = Vec::with_capacity(n);
+    for k in 1..=n {
+        let row = (m + k) as isize;
+        matrix.push((1..=n).map(|j| c(row - j as isize)).collect());
+        rhs.push(-c(row));
+    }
+    let mut q = vec![T::one()];
+    q.extend(linalg::solve(matrix, rhs).ok_or(PadeError::Singular)?);
+
+    // The numerator is then the product of the series and the
+    // denominator, truncated after x^m
+    let mut p = vec![T::zero(); m + 1];
+    for (i, value) in p.iter_mut().enumerate() {
+        for (j, qj) in q.iter().enumerate().take(i + 1) {
+            *value = *value + *qj * coefficients[i - j];
+        }
+    }
+
+    Ok(RationalFunction::new(
+        Polynomial::from_coefficients(&p),
+        Polynomial::from_coefficients(&q),
+    ))
+}
+
+impl<T> PowerSeries<T>
+where
+    T: Float + Pow<T, Output = T>,
+{
+    /// Returns the `[m/n]` Padé approximant of the series, see
+    /// [pade](crate::pade::pade)
+    pub fn pade(&self, m: usize, n: usize) -> Result<RationalFunction<T>, PadeError> {
+        pade(self.coefficients(), m, n)
+    }
+}
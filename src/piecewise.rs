@@ -0,0 +1,257 @@
+//! Functions defined by different formulas on different intervals,
+//! such as `x^2` for `x < 0` and `3x + 1` otherwise, where each
+//! formula is any [TermTrait](../traits/trait.TermTrait.html)
+//! implementor.
+
+use core::ops::{Bound, RangeBounds};
+
+use num_traits::{identities::zero, Num, Pow};
+
+use crate::traits::{Differentiation, Integration, TermTrait};
+
+/// The lower and upper bound of the interval a piece is used on
+pub type Interval<T> = (Bound<T>, Bound<T>);
+
+/// Returns whether `x` lies between the two bounds
+fn contains<T: PartialOrd>(interval: &Interval<T>, x: &T) -> bool {
+    let above = match &interval.0 {
+        Bound::Included(lower) => x >= lower,
+        Bound::Excluded(lower) => x > lower,
+        Bound::Unbounded => true,
+    };
+    let below = match &interval.1 {
+        Bound::Included(upper) => x <= upper,
+        Bound::Excluded(upper) => x < upper,
+        Bound::Unbounded => true,
+    };
+    above && below
+}
+
+/// Returns the value of a bound, if it has one
+fn value<T: Copy>(bound: &Bound<T>) -> Option<T> {
+    match bound {
+        Bound::Included(x) | Bound::Excluded(x) => Some(*x),
+        Bound::Unbounded => None,
+    }
+}
+
+/// Returns `|x|` without needing `Signed`
+fn abs<T: Num + PartialOrd>(x: T) -> T {
+    if x < zero() {
+        zero::<T>() - x
+    } else {
+        x
+    }
+}
+
+/// A function made of pieces, each used on its own interval. Where
+/// intervals overlap, the piece added first is used
+#[derive(Debug, Clone, PartialEq)]
+pub struct Piecewise<T, F> {
+    pieces: Vec<(Interval<T>, F)>,
+}
+
+impl<T, F> Default for Piecewise<T, F> {
+    fn default() -> Self {
+        Self { pieces: Vec::new() }
+    }
+}
+
+impl<T, F> Piecewise<T, F>
+where
+    T: Num + Pow<T, Output = T> + Copy + PartialOrd,
+{
+    /// Constructs a new piecewise function with no pieces
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Adds `piece`, used wherever x lies in `range`, such as `..0.`
+    /// for x < 0 or `0.0..` for x >= 0
+    /// ```
+    /// use cakcukus::{piecewise::Piecewise, terms, traits::TermTrait, Polynomial};
+    ///
+    /// let square: Polynomial<f64> = Polynomial(vec![terms!(1., 2.)]);
+    /// let line: Polynomial<f64> = terms!(3., 1., 1., 0.);
+    /// let f = Piecewise::new().with_piece(..0., square).with_piece(0.0.., line);
+    ///
+    /// assert_eq!(f.sum_with_respect_to(&-2.), 4.);
+    /// assert_eq!(f.sum_with_respect_to(&2.), 7.);
+    /// ```
+    pub fn with_piece<R: RangeBounds<T>>(mut self, range: R, piece: F) -> Self {
+        self.push(range, piece);
+        self
+    }
+    /// Adds `piece`, used wherever x lies in `range`
+    pub fn push<R: RangeBounds<T>>(&mut self, range: R, piece: F) {
+        let interval = (range.start_bound().cloned(), range.end_bound().cloned());
+        self.pieces.push((interval, piece));
+    }
+    /// The pieces, along with the interval each is used on
+    pub fn pieces(&self) -> &[(Interval<T>, F)] {
+        &self.pieces
+    }
+    /// Returns the piece used at `x`, if any
+    pub fn piece_at(&self, x: &T) -> Option<&F> {
+        self.pieces
+            .iter()
+            .find(|(interval, _)| contains(interval, x))
+            .map(|(_, piece)| piece)
+    }
+    /// Returns the finite interval ends of every piece, sorted and
+    /// without repeats
+    pub fn breakpoints(&self) -> Vec<T> {
+        let mut breakpoints: Vec<T> = self
+            .pieces
+            .iter()
+            .flat_map(|(interval, _)| vec![value(&interval.0), value(&interval.1)])
+            .flatten()
+            .collect();
+        breakpoints.sort_by(|a, b| a.partial_cmp(b).unwrap_or(core::cmp::Ordering::Equal));
+        breakpoints.dedup();
+        breakpoints
+    }
+    /// Returns each pair of pieces that meet, as the point they meet
+    /// at, the piece ending there, and the piece starting there
+    fn joins(&self) -> Vec<(T, &F, &F)> {
+        let mut joins = Vec::new();
+        for (left_interval, left) in self.pieces.iter() {
+            for (right_interval, right) in self.pieces.iter() {
+                if let (Some(end), Some(start)) =
+                    (value(&left_interval.1), value(&right_interval.0))
+                {
+                    if end == start {
+                        joins.push((end, left, right));
+                    }
+                }
+            }
+        }
+        joins
+    }
+}
+
+impl<T, F> Piecewise<T, F>
+where
+    T: Num + Pow<T, Output = T> + Copy + PartialOrd,
+    F: TermTrait<T>,
+{
+    /// Returns the value at `x`, or None if no piece covers it
+    pub fn evaluate(&self, x: &T) -> Option<T> {
+        self.piece_at(x).map(|piece| piece.sum_with_respect_to(x))
+    }
+    /// Returns whether the pieces meeting at each join agree there,
+    /// to within `tolerance`
+    pub fn is_continuous(&self, tolerance: T) -> bool {
+        self.joins().into_iter().all(|(at, left, right)| {
+            abs(left.sum_with_respect_to(&at) - right.sum_with_respect_to(&at)) <= tolerance
+        })
+    }
+}
+
+impl<T, F> Piecewise<T, F>
+where
+    T: Num + Pow<T, Output = T> + Copy + PartialOrd,
+    F: TermTrait<T> + Differentiation<T>,
+{
+    /// Returns whether the function is continuous, and the pieces
+    /// meeting at each join have the same slope there, to within
+    /// `tolerance`
+    pub fn is_differentiable(&self, tolerance: T) -> bool {
+        self.is_continuous(tolerance)
+            && self.joins().into_iter().all(|(at, left, right)| {
+                abs(left.differentiate(&at) - right.differentiate(&at)) <= tolerance
+            })
+    }
+}
+
+impl<T, F> TermTrait<T> for Piecewise<T, F>
+where
+    T: Num + Pow<T, Output = T> + Copy + PartialOrd,
+    F: TermTrait<T>,
+{
+    /// # Panics
+    /// If no piece covers `x`
+    fn sum_with_respect_to(&self, x: &T) -> T {
+        self.evaluate(x)
+            .expect("No piece of the function covers the point")
+    }
+}
+
+impl<T, F> Differentiation<T> for Piecewise<T, F>
+where
+    T: Num + Pow<T, Output = T> + Copy + PartialOrd,
+    F: Differentiation<T>,
+{
+    /// Differentiates the piece covering `x`. At a join, this is the
+    /// slope of the piece used there, whether or not the function is
+    /// differentiable
+    ///
+    /// # Panics
+    /// If no piece covers `x`
+    fn differentiate(&self, x: &T) -> T {
+        self.piece_at(x)
+            .expect("No piece of the function covers the point")
+            .differentiate(x)
+    }
+    /// Differentiates each piece, keeping their intervals
+    fn differentiate_self(&self) -> Self {
+        Self {
+            pieces: self
+                .pieces
+                .iter()
+                .map(|(interval, piece)| (*interval, piece.differentiate_self()))
+                .collect(),
+        }
+    }
+}
+
+impl<T, F> Integration<T> for Piecewise<T, F>
+where
+    T: Num + Pow<T, Output = T> + Copy + PartialOrd,
+    F: Integration<T>,
+{
+    /// Intergrates each piece, keeping their intervals. Each piece
+    /// has a constant of 0, so the result usually isn't continuous,
+    /// but [integrate](#method.integrate) still handles the joins
+    fn integrate_self(&self) -> Self {
+        Self {
+            pieces: self
+                .pieces
+                .iter()
+                .map(|(interval, piece)| (*interval, piece.integrate_self()))
+                .collect(),
+        }
+    }
+    /// Intergrates between `lower` and `upper`, splitting the range
+    /// at every breakpoint and using the piece covering each part
+    ///
+    /// # Panics
+    /// If part of the range isn't covered by any piece
+    fn integrate(&self, lower: T, upper: T) -> T {
+        if upper < lower {
+            return zero::<T>() - self.integrate(upper, lower);
+        }
+        let two = T::one() + T::one();
+        let mut points = vec![lower];
+        points.extend(
+            self.breakpoints()
+                .into_iter()
+                .filter(|x| *x > lower && *x < upper),
+        );
+        points.push(upper);
+
+        let mut total = zero();
+        for pair in points.windows(2) {
+            if pair[0] == pair[1] {
+                continue;
+            }
+            // The middle of each part decides which piece covers it,
+            // as the ends may belong to the neighbours
+            let middle = (pair[0] + pair[1]) / two;
+            let piece = self
+                .piece_at(&middle)
+                .expect("No piece of the function covers the range");
+            total = total + piece.integrate(pair[0], pair[1]);
+        }
+        total
+    }
+}
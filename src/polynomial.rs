@@ -1,4 +1,4 @@
-use crate::Term;
+use crate::{util::from_u32, Term};
 
 use num_traits::{identities::zero, Num, Pow};
 
@@ -17,6 +17,42 @@ where
     pub fn with_capacity(capacity: usize) -> Self {
         Self(Vec::with_capacity(capacity))
     }
+    /// Builds a simplified polynomial from its coefficients, with the
+    /// nth coefficient belonging to x^n, skipping any that are 0
+    pub(crate) fn from_coefficients(coefficients: &[T]) -> Self {
+        let mut terms = Vec::with_capacity(coefficients.len());
+        for (power, coefficient) in coefficients.iter().enumerate().rev() {
+            if !coefficient.is_zero() {
+                terms.push(Term::new(coefficient.clone(), from_u32(power as u32)));
+            }
+        }
+        Self(terms)
+    }
+    /// Returns the coefficients of the simplified polynomial, with
+    /// the nth belonging to x^n, or None if any exponent isn't a
+    /// whole number of at least 0
+    pub(crate) fn dense_coefficients(&self) -> Option<Vec<T>> {
+        let mut simplified = self.clone();
+        simplified.simplify();
+        let mut coefficients = Vec::new();
+        let mut power = zero::<T>();
+        // Simplifying leaves the exponents in descending order
+        for term in simplified.0.iter().rev() {
+            if term.coefficient.is_zero() {
+                continue;
+            }
+            while power < term.exponent {
+                coefficients.push(zero());
+                power = power + T::one();
+            }
+            if power != term.exponent {
+                return None;
+            }
+            coefficients.push(term.coefficient.clone());
+            power = power + T::one();
+        }
+        Some(coefficients)
+    }
 }
 
 impl<T> Default for Polynomial<T>
@@ -204,6 +240,170 @@ mod impl_std_traits {
             }
         }
     }
+    mod pow {
+        use super::{Num, Polynomial, Pow, Term};
+        use crate::{
+            composition::{tidy, whole},
+            util::from_u32,
+        };
+        use num_traits::identities::{one, zero};
+
+        /// Bases with at most this many terms are expanded with the
+        /// multinomial theorem rather than by squaring
+        const MULTINOMIAL_TERMS: usize = 4;
+
+        /// Returns `term^0, term^1, ..., term^n`
+        fn powers<T>(term: &Term<T>, n: u32) -> Vec<Term<T>>
+        where
+            T: Num + Pow<T, Output = T> + Clone + PartialOrd,
+        {
+            let mut powers = Vec::with_capacity(n as usize + 1);
+            powers.push(Term::new(one(), zero()));
+            for k in 0..n as usize {
+                powers.push(powers[k].clone() * term.clone());
+            }
+            powers
+        }
+
+        /// Returns `(a + b)^n` by the binomial theorem, from the `a^n`
+        /// term down, so when `a`'s exponent is the larger the terms
+        /// come out in order with no like terms to combine
+        fn binomial<T>(a: &Term<T>, b: &Term<T>, n: u32) -> Polynomial<T>
+        where
+            T: Num + Pow<T, Output = T> + Clone + PartialOrd,
+        {
+            let (a_powers, b_powers) = (powers(a, n), powers(b, n));
+            // C(n, k), for k counting the powers of b
+            let mut choose = one::<T>();
+            let mut expanded = Vec::with_capacity(n as usize + 1);
+            for k in 0..=n {
+                let term = a_powers[(n - k) as usize].clone() * b_powers[k as usize].clone();
+                expanded.push(Term::new(choose.clone(), zero()) * term);
+                if k < n {
+                    choose = choose * from_u32(n - k) / from_u32(k + 1);
+                }
+            }
+            tidy(Polynomial(expanded))
+        }
+
+        /// Returns `(t1 + t2 + ...)^n` for tidied `terms` by the
+        /// multinomial theorem, choosing how many times the first term
+        /// appears and multiplying it by the matching power of the rest.
+        /// Like terms are combined as they're found, in a dense list of
+        /// coefficients, so this returns None if the exponents aren't a
+        /// whole number apart, or if that list would be longer than the
+        /// multinomial terms it replaces
+        fn multinomial<T>(terms: &[Term<T>], n: u32) -> Option<Polynomial<T>>
+        where
+            T: Num + Pow<T, Output = T> + Clone + PartialOrd,
+        {
+            let lowest = terms[terms.len() - 1].exponent.clone();
+            // How many powers of x each term is above the lowest
+            let mut offsets = Vec::with_capacity(terms.len());
+            for term in terms {
+                offsets.push(whole(&(term.exponent.clone() - lowest.clone()))? as usize);
+            }
+            let (first, rest) = (&terms[0].coefficient, &terms[1..]);
+            let span = offsets[0];
+            // The number of multinomial terms, C(n + m - 1, m - 1)
+            let mut count = 1u128;
+            for j in 1..terms.len() as u128 {
+                count = count * (u128::from(n) + j) / j;
+            }
+            if u128::from(n) * span as u128 + 1 > count {
+                return None;
+            }
+
+            let mut first_powers = vec![one::<T>()];
+            for k in 0..n as usize {
+                first_powers.push(first_powers[k].clone() * first.clone());
+            }
+            // (t2 + t3 + ...)^k, with x^(k lowest) factored out
+            let mut rest_power = vec![one::<T>()];
+            let mut expanded = vec![zero::<T>(); n as usize * span + 1];
+            let mut choose = one::<T>();
+            for k in 0..=n {
+                // C(n, k) t1^(n - k) (t2 + t3 + ...)^k
+                let scale = choose.clone() * first_powers[(n - k) as usize].clone();
+                let shift = (n - k) as usize * span;
+                for (j, coefficient) in rest_power.iter().enumerate() {
+                    expanded[shift + j] =
+                        expanded[shift + j].clone() + scale.clone() * coefficient.clone();
+                }
+                if k == n {
+                    break;
+                }
+                choose = choose * from_u32(n - k) / from_u32(k + 1);
+                let mut next = vec![zero::<T>(); rest_power.len() + offsets[1]];
+                for (j, coefficient) in rest_power.iter().enumerate() {
+                    for (term, offset) in rest.iter().zip(&offsets[1..]) {
+                        next[j + offset] = next[j + offset].clone()
+                            + coefficient.clone() * term.coefficient.clone();
+                    }
+                }
+                rest_power = next;
+            }
+
+            let mut exponent = from_u32::<T>(n) * lowest;
+            let mut result = Vec::with_capacity(expanded.len());
+            for coefficient in expanded {
+                if !coefficient.is_zero() {
+                    result.push(Term::new(coefficient, exponent.clone()));
+                }
+                exponent = exponent + one();
+            }
+            result.reverse();
+            Some(Polynomial(result))
+        }
+
+        impl<T: Num + Pow<T, Output = T> + Clone + PartialOrd> Pow<u32> for Polynomial<T> {
+            type Output = Self;
+
+            /// Raises the polynomial to the nth power, simplified. Bases
+            /// of two terms are expanded directly with the binomial
+            /// theorem, bases of a few terms with the multinomial theorem
+            /// when their exponents are a whole number apart, and any
+            /// others by repeated squaring
+            /// ```
+            /// use cakcukus::{terms, Polynomial};
+            /// use num_traits::Pow;
+            ///
+            /// let p: Polynomial<f64> = terms!(1., 1., 1., 0.);
+            /// let expanded = p.pow(20u32);
+            ///
+            /// assert_eq!(expanded.0.len(), 21);
+            /// assert_eq!(expanded.0[10], terms!(184_756., 10.));
+            /// ```
+            fn pow(self, n: u32) -> Self::Output {
+                let base = tidy(self);
+                let mut result = Self(vec![Term::new(one(), zero())]);
+                if base.0.is_empty() {
+                    return if n == 0 { result } else { base };
+                }
+                match base.0.len() {
+                    2 => return binomial(&base.0[0], &base.0[1], n),
+                    3..=MULTINOMIAL_TERMS => {
+                        if let Some(expanded) = multinomial(&base.0, n) {
+                            return expanded;
+                        }
+                    }
+                    _ => {}
+                }
+                let mut square = base;
+                let mut n = n;
+                while n > 0 {
+                    if n & 1 == 1 {
+                        result = tidy(result * square.clone());
+                    }
+                    n >>= 1;
+                    if n > 0 {
+                        square = tidy(square.clone() * square);
+                    }
+                }
+                result
+            }
+        }
+    }
     mod sub {
         use super::{Neg, Num, Polynomial, Pow, Term};
         use std::ops::{Sub, SubAssign};
@@ -0,0 +1,312 @@
+use crate::{
+    traits::{Differentiation, Integration, TermTrait},
+    util::from_u32,
+    Polynomial,
+};
+
+use num_traits::{identities::zero, Float, Num, Pow};
+
+/// A truncated power series, `a0 + a1x + a2x^2 + ... + O(x^n)`,
+/// where n is the series' precision.
+///
+/// Arithmetic between two series keeps the smaller precision, as
+/// any coefficient past it isn't known
+#[derive(Debug, Clone, PartialEq)]
+pub struct PowerSeries<T>
+where
+    T: Num + Pow<T, Output = T> + Copy,
+{
+    coefficients: Vec<T>,
+}
+
+impl<T> PowerSeries<T>
+where
+    T: Num + Pow<T, Output = T> + Copy,
+{
+    /// Constructs a new series from its coefficients, with the nth
+    /// coefficient belonging to x^n. The precision is the number of
+    /// coefficients given
+    pub fn new(coefficients: Vec<T>) -> Self {
+        Self { coefficients }
+    }
+    /// The series for x, known to the given precision
+    pub fn x(precision: usize) -> Self {
+        let mut coefficients = vec![zero(); precision];
+        if precision > 1 {
+            coefficients[1] = T::one();
+        }
+        Self::new(coefficients)
+    }
+    /// The number of known coefficients
+    pub fn precision(&self) -> usize {
+        self.coefficients.len()
+    }
+    /// Returns the coefficient of x^n, or 0 past the precision
+    pub fn coefficient(&self, n: usize) -> T {
+        self.coefficients.get(n).copied().unwrap_or_else(zero)
+    }
+    pub fn coefficients(&self) -> &[T] {
+        &self.coefficients
+    }
+    /// Drops every coefficient past `precision`
+    pub fn truncate(&mut self, precision: usize) {
+        self.coefficients.truncate(precision);
+    }
+    /// Returns the series `1 / self`, or None if the constant
+    /// coefficient is 0
+    pub fn reciprocal(&self) -> Option<Self> {
+        let a0 = self.coefficient(0);
+        if a0.is_zero() {
+            return None;
+        }
+        let mut result: Vec<T> = Vec::with_capacity(self.precision());
+        for n in 0..self.precision() {
+            let mut total = if n == 0 { T::one() } else { zero() };
+            for k in 1..=n {
+                total = total - self.coefficients[k] * result[n - k];
+            }
+            result.push(total / a0);
+        }
+        Some(Self::new(result))
+    }
+    /// Returns the series `self(inner(x))`, or None if `inner`'s
+    /// constant coefficient isn't 0, as the result couldn't be
+    /// truncated
+    pub fn compose(&self, inner: &Self) -> Option<Self> {
+        if !inner.coefficient(0).is_zero() {
+            return None;
+        }
+        let precision = self.precision().min(inner.precision());
+        // Horner's method, from the highest coefficient down
+        let mut result = Self::new(vec![zero(); precision]);
+        for coefficient in self.coefficients.iter().rev() {
+            result = result * inner.clone();
+            if precision != 0 {
+                result.coefficients[0] = result.coefficients[0] + *coefficient;
+            }
+        }
+        Some(result)
+    }
+    /// Returns the compositional inverse `g`, with `self(g(x)) = x`,
+    /// or None unless the constant coefficient is 0 and the
+    /// coefficient of x isn't
+    /// ```
+    /// use cakcukus::PowerSeries;
+    ///
+    /// // x + x^2 has the inverse x - x^2 + 2x^3 - 5x^4 + ...
+    /// let series = PowerSeries::new(vec![0., 1., 1., 0., 0.]);
+    ///
+    /// assert_eq!(series.reversion().unwrap().coefficients(), &[0., 1., -1., 2., -5.]);
+    /// ```
+    pub fn reversion(&self) -> Option<Self> {
+        let a1 = self.coefficient(1);
+        if !self.coefficient(0).is_zero() || a1.is_zero() {
+            return None;
+        }
+        let precision = self.precision();
+        let mut inverse = Self::x(precision);
+        if precision > 1 {
+            inverse.coefficients[1] = T::one() / a1;
+        }
+        // Each coefficient only affects the composition from its
+        // own power up, so they can be fixed one at a time
+        for n in 2..precision {
+            let composed = self.compose(&inverse)?;
+            inverse.coefficients[n] = zero::<T>() - composed.coefficient(n) / a1;
+        }
+        Some(inverse)
+    }
+    /// Converts the known coefficients into a polynomial
+    pub fn to_polynomial(&self) -> Polynomial<T>
+    where
+        T: PartialOrd,
+    {
+        Polynomial::from_coefficients(&self.coefficients)
+    }
+}
+
+impl<T> PowerSeries<T>
+where
+    T: Float + Pow<T, Output = T>,
+{
+    /// Returns the series `e^self`, found from `g' = self' g`
+    pub fn exp(&self) -> Self {
+        let mut result: Vec<T> = Vec::with_capacity(self.precision());
+        for n in 0..self.precision() {
+            if n == 0 {
+                result.push(self.coefficients[0].exp());
+                continue;
+            }
+            let mut total = T::zero();
+            for k in 1..=n {
+                total = total + from_u32::<T>(k as u32) * self.coefficients[k] * result[n - k];
+            }
+            result.push(total / from_u32(n as u32));
+        }
+        Self::new(result)
+    }
+    /// Returns the series `ln(self)`, or None unless the constant
+    /// coefficient is positive
+    pub fn ln(&self) -> Option<Self> {
+        let a0 = self.coefficient(0);
+        if a0 <= T::zero() {
+            return None;
+        }
+        let mut result = (self.differentiate_self() * self.reciprocal()?).integrate_self();
+        if result.precision() != 0 {
+            result.coefficients[0] = a0.ln();
+        }
+        Some(result)
+    }
+    /// Returns the series `sqrt(self)`, or None unless the constant
+    /// coefficient is positive
+    pub fn sqrt(&self) -> Option<Self> {
+        let a0 = self.coefficient(0);
+        if a0 <= T::zero() {
+            return None;
+        }
+        let root = a0.sqrt();
+        let mut result: Vec<T> = Vec::with_capacity(self.precision());
+        for n in 0..self.precision() {
+            if n == 0 {
+                result.push(root);
+                continue;
+            }
+            let mut total = self.coefficients[n];
+            for k in 1..n {
+                total = total - result[k] * result[n - k];
+            }
+            result.push(total / (root + root));
+        }
+        Some(Self::new(result))
+    }
+}
+
+impl<T> TermTrait<T> for PowerSeries<T>
+where
+    T: Num + Pow<T, Output = T> + Copy,
+{
+    /// Sums the known terms, with Horner's method
+    fn sum_with_respect_to(&self, x: &T) -> T {
+        let mut total = zero();
+        for coefficient in self.coefficients.iter().rev() {
+            total = total * *x + *coefficient;
+        }
+        total
+    }
+}
+
+impl<T> Differentiation<T> for PowerSeries<T>
+where
+    T: Num + Pow<T, Output = T> + Copy,
+{
+    fn differentiate(&self, x: &T) -> T {
+        self.differentiate_self().sum_with_respect_to(x)
+    }
+    /// Differentiates a copy of self, which is known to one less
+    /// coefficient
+    fn differentiate_self(&self) -> Self {
+        Self::new(
+            self.coefficients
+                .iter()
+                .enumerate()
+                .skip(1)
+                .map(|(power, c)| *c * from_u32(power as u32))
+                .collect(),
+        )
+    }
+}
+
+impl<T> Integration<T> for PowerSeries<T>
+where
+    T: Num + Pow<T, Output = T> + Copy,
+{
+    /// Intergrates a copy of self, with a constant of 0, which is
+    /// known to one more coefficient
+    fn integrate_self(&self) -> Self {
+        let mut coefficients = Vec::with_capacity(self.precision() + 1);
+        coefficients.push(zero());
+        for (power, c) in self.coefficients.iter().enumerate() {
+            coefficients.push(*c / from_u32(power as u32 + 1));
+        }
+        Self::new(coefficients)
+    }
+    fn integrate(&self, lower: T, upper: T) -> T {
+        let integrated = self.integrate_self();
+        integrated.sum_with_respect_to(&upper) - integrated.sum_with_respect_to(&lower)
+    }
+}
+
+mod impl_std_traits {
+    use super::PowerSeries;
+    use core::ops::{Add, Div, Mul, Neg, Sub};
+
+    use num_traits::{identities::zero, Num, Pow};
+
+    impl<T: Num + Pow<T, Output = T> + Copy> Add for PowerSeries<T> {
+        type Output = Self;
+
+        fn add(self, rhs: Self) -> Self::Output {
+            let precision = self.precision().min(rhs.precision());
+            Self::new(
+                (0..precision)
+                    .map(|n| self.coefficients[n] + rhs.coefficients[n])
+                    .collect(),
+            )
+        }
+    }
+    impl<T: Num + Pow<T, Output = T> + Copy> Sub for PowerSeries<T> {
+        type Output = Self;
+
+        fn sub(self, rhs: Self) -> Self::Output {
+            let precision = self.precision().min(rhs.precision());
+            Self::new(
+                (0..precision)
+                    .map(|n| self.coefficients[n] - rhs.coefficients[n])
+                    .collect(),
+            )
+        }
+    }
+    impl<T: Num + Pow<T, Output = T> + Copy> Mul for PowerSeries<T> {
+        type Output = Self;
+
+        /// Multiplies the series, dropping every term past the
+        /// smaller precision
+        fn mul(self, rhs: Self) -> Self::Output {
+            let precision = self.precision().min(rhs.precision());
+            let mut coefficients = vec![zero(); precision];
+            for (i, a) in self.coefficients.iter().take(precision).enumerate() {
+                for (j, b) in rhs.coefficients.iter().take(precision - i).enumerate() {
+                    coefficients[i + j] = coefficients[i + j] + *a * *b;
+                }
+            }
+            Self::new(coefficients)
+        }
+    }
+    impl<T: Num + Pow<T, Output = T> + Copy> Mul<T> for PowerSeries<T> {
+        type Output = Self;
+
+        fn mul(self, rhs: T) -> Self::Output {
+            Self::new(self.coefficients.iter().map(|c| *c * rhs).collect())
+        }
+    }
+    impl<T: Num + Pow<T, Output = T> + Copy> Div for PowerSeries<T> {
+        type Output = Self;
+
+        /// # Panics
+        /// If `rhs` has a constant coefficient of 0
+        #[allow(clippy::suspicious_arithmetic_impl)]
+        fn div(self, rhs: Self) -> Self::Output {
+            self * rhs
+                .reciprocal()
+                .expect("Division by a series with no constant term")
+        }
+    }
+    impl<T: Num + Pow<T, Output = T> + Copy + Neg<Output = T>> Neg for PowerSeries<T> {
+        type Output = Self;
+
+        fn neg(self) -> Self::Output {
+            Self::new(self.coefficients.iter().map(|c| -*c).collect())
+        }
+    }
+}
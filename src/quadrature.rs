@@ -0,0 +1,527 @@
+//! Numerical integration, for closures `Fn(T) -> T` and, through
+//! [NumericalIntegration](trait.NumericalIntegration.html), any
+//! [TermTrait](../traits/trait.TermTrait.html) implementor, including
+//! those that can't be intergrated symbolically.
+
+use core::{
+    cell::Cell,
+    fmt::{Debug, Display},
+};
+
+use num_traits::{Float, Num, Pow};
+
+use crate::{traits::TermTrait, util::cast};
+
+/// The result of an integration that also estimates its own error
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Estimate<T> {
+    /// The estimated value of the integral
+    pub value: T,
+
+    /// An estimate of the absolute error in `value`
+    pub error: T,
+}
+
+/// Describes why a numerical integration failed
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum QuadratureError<T> {
+    /// The requested tolerance wasn't reached before running out of
+    /// refinements, holding the best estimate found
+    NotConverged(Estimate<T>),
+    /// The integral grows without bound
+    Divergent,
+    /// The integrand wasn't finite somewhere inside the range, such as
+    /// a square root sampled below 0
+    OutsideDomain,
+}
+
+impl<T: Display> Display for QuadratureError<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            QuadratureError::NotConverged(estimate) => write!(
+                f,
+                "integration did not converge, best estimate {} with error {}",
+                estimate.value, estimate.error
+            ),
+            QuadratureError::Divergent => write!(f, "the integral does not converge"),
+            QuadratureError::OutsideDomain => {
+                write!(f, "the integrand is not finite inside the range")
+            }
+        }
+    }
+}
+
+impl<T: Debug + Display> std::error::Error for QuadratureError<T> {}
+
+/// Intergrates `f` between `lower` and `upper` with the composite
+/// trapezoid rule, over `intervals` equal intervals
+///
+/// # Panics
+/// If `intervals` is 0
+pub fn trapezoid<T: Float, F: Fn(T) -> T>(f: F, lower: T, upper: T, intervals: usize) -> T {
+    assert!(intervals != 0, "trapezoid needs at least one interval");
+    let width = (upper - lower) / cast(intervals as f64);
+    let mut total = (f(lower) + f(upper)) / cast(2.);
+    for i in 1..intervals {
+        total = total + f(lower + width * cast(i as f64));
+    }
+    total * width
+}
+
+/// Intergrates `f` between `lower` and `upper` with the composite
+/// Simpson's rule, over `intervals` equal intervals. An odd number
+/// of intervals is rounded up to the next even number
+/// ```
+/// use cakcukus::quadrature::simpson;
+///
+/// // Simpson's rule is exact for cubics
+/// let integral = simpson(|x: f64| x * x * x, 0., 2., 2);
+///
+/// assert!((integral - 4.).abs() < 1e-12);
+/// ```
+pub fn simpson<T: Float, F: Fn(T) -> T>(f: F, lower: T, upper: T, intervals: usize) -> T {
+    let intervals = intervals.max(2);
+    let intervals = intervals + intervals % 2;
+    let width = (upper - lower) / cast(intervals as f64);
+    let mut total = f(lower) + f(upper);
+    for i in 1..intervals {
+        let weight = if i % 2 == 1 { 4. } else { 2. };
+        total = total + cast::<T, _>(weight) * f(lower + width * cast(i as f64));
+    }
+    total * width / cast(3.)
+}
+
+/// Intergrates `f` between `lower` and `upper` with Romberg's method,
+/// repeatedly halving the trapezoid rule's step and extrapolating,
+/// until two successive estimates are within `tolerance`
+pub fn romberg<T: Float, F: Fn(T) -> T>(
+    f: F,
+    lower: T,
+    upper: T,
+    tolerance: T,
+    max_steps: usize,
+) -> Result<Estimate<T>, QuadratureError<T>> {
+    let mut width = upper - lower;
+    let mut previous = vec![(f(lower) + f(upper)) * width / cast(2.)];
+    let mut estimate = Estimate {
+        value: previous[0],
+        error: T::infinity(),
+    };
+    for step in 1..=max_steps {
+        // The trapezoid rule with half the step reuses every
+        // previous point, so only the new midpoints are needed
+        let points = match 1usize.checked_shl((step - 1) as u32) {
+            Some(points) => points,
+            None => break,
+        };
+        width = width / cast(2.);
+        let mut midpoints = T::zero();
+        for i in 0..points {
+            midpoints = midpoints + f(lower + width * cast((2 * i + 1) as f64));
+        }
+        let mut row = Vec::with_capacity(step + 1);
+        row.push(previous[0] / cast(2.) + midpoints * width);
+        let mut factor = T::one();
+        for k in 1..=step {
+            factor = factor * cast(4.);
+            let extrapolated = row[k - 1] + (row[k - 1] - previous[k - 1]) / (factor - T::one());
+            row.push(extrapolated);
+        }
+        estimate = Estimate {
+            value: row[step],
+            error: (row[step] - previous[step - 1]).abs(),
+        };
+        if estimate.error <= tolerance {
+            return Ok(estimate);
+        }
+        previous = row;
+    }
+    Err(QuadratureError::NotConverged(estimate))
+}
+
+/// Returns the nodes and weights of the Gauss–Legendre rule with
+/// `order` points on [-1, 1], found by Newton's method on the
+/// Legendre polynomial of degree `order`
+///
+/// # Panics
+/// If `order` is 0
+pub fn gauss_legendre_nodes<T: Float>(order: usize) -> Vec<(T, T)> {
+    assert!(order != 0, "gauss_legendre needs at least one point");
+    let n = order as f64;
+    let mut nodes = Vec::with_capacity(order);
+    for i in 1..=order {
+        let mut x: T = cast((core::f64::consts::PI * (i as f64 - 0.25) / (n + 0.5)).cos());
+        let mut derivative = T::one();
+        for _ in 0..100 {
+            // The three term recurrence gives P_n(x) and P_n-1(x)
+            let (mut p, mut previous) = (x, T::one());
+            for k in 2..=order {
+                let k = cast::<T, _>(k as f64);
+                let next =
+                    ((cast::<T, _>(2.) * k - T::one()) * x * p - (k - T::one()) * previous) / k;
+                previous = p;
+                p = next;
+            }
+            derivative = cast::<T, _>(n) * (x * p - previous) / (x * x - T::one());
+            let step = p / derivative;
+            x = x - step;
+            if step.abs() <= T::epsilon() {
+                break;
+            }
+        }
+        let weight = cast::<T, _>(2.) / ((T::one() - x * x) * derivative * derivative);
+        nodes.push((x, weight));
+    }
+    nodes
+}
+
+/// Intergrates `f` between `lower` and `upper` with the Gauss–Legendre
+/// rule with `order` points, which is exact for polynomials of
+/// degree up to `2 * order - 1`
+///
+/// # Panics
+/// If `order` is 0
+pub fn gauss_legendre<T: Float, F: Fn(T) -> T>(f: F, lower: T, upper: T, order: usize) -> T {
+    let half_width = (upper - lower) / cast(2.);
+    let centre = (upper + lower) / cast(2.);
+    let mut total = T::zero();
+    for (node, weight) in gauss_legendre_nodes::<T>(order) {
+        total = total + weight * f(centre + half_width * node);
+    }
+    total * half_width
+}
+
+// The 15 point Kronrod extension of the 7 point Gauss rule, with the
+// Gauss nodes at the odd indices
+const KRONROD_NODES: [f64; 8] = [
+    0.991_455_371_120_812_6,
+    0.949_107_912_342_758_5,
+    0.864_864_423_359_769_1,
+    0.741_531_185_599_394_4,
+    0.586_087_235_467_691_1,
+    0.405_845_151_377_397_2,
+    0.207_784_955_007_898_5,
+    0.,
+];
+const KRONROD_WEIGHTS: [f64; 8] = [
+    0.022_935_322_010_529_22,
+    0.063_092_092_629_978_55,
+    0.104_790_010_322_250_2,
+    0.140_653_259_715_525_9,
+    0.169_004_726_639_267_9,
+    0.190_350_578_064_785_4,
+    0.204_432_940_075_298_9,
+    0.209_482_141_084_727_8,
+];
+const GAUSS_WEIGHTS: [f64; 4] = [
+    0.129_484_966_168_869_7,
+    0.279_705_391_489_276_7,
+    0.381_830_050_505_118_9,
+    0.417_959_183_673_469_4,
+];
+
+/// Applies the 7-15 Gauss–Kronrod pair to a single interval, with
+/// the difference between the two as the error estimate
+fn kronrod_15<T: Float, F: Fn(T) -> T>(f: &F, lower: T, upper: T) -> Estimate<T> {
+    let half_width = (upper - lower) / cast(2.);
+    let centre = (upper + lower) / cast(2.);
+    let f_centre = f(centre);
+    let mut kronrod = f_centre * cast(KRONROD_WEIGHTS[7]);
+    let mut gauss = f_centre * cast(GAUSS_WEIGHTS[3]);
+    for i in 0..7 {
+        let offset = half_width * cast(KRONROD_NODES[i]);
+        let sum = f(centre - offset) + f(centre + offset);
+        kronrod = kronrod + sum * cast(KRONROD_WEIGHTS[i]);
+        if i % 2 == 1 {
+            gauss = gauss + sum * cast(GAUSS_WEIGHTS[i / 2]);
+        }
+    }
+    Estimate {
+        value: kronrod * half_width,
+        error: ((kronrod - gauss) * half_width).abs(),
+    }
+}
+
+/// Intergrates `f` between `lower` and `upper` with adaptive 7-15
+/// Gauss–Kronrod quadrature. The interval with the largest error is
+/// repeatedly halved, until the total error is within `tolerance`
+/// or `max_subdivisions` halvings have been made
+/// ```
+/// use cakcukus::quadrature::gauss_kronrod;
+///
+/// let estimate = gauss_kronrod(|x: f64| x.sin(), 0., core::f64::consts::PI, 1e-10, 50).unwrap();
+///
+/// assert!((estimate.value - 2.).abs() < 1e-10);
+/// ```
+pub fn gauss_kronrod<T: Float, F: Fn(T) -> T>(
+    f: F,
+    lower: T,
+    upper: T,
+    tolerance: T,
+    max_subdivisions: usize,
+) -> Result<Estimate<T>, QuadratureError<T>> {
+    let mut intervals = vec![(lower, upper, kronrod_15(&f, lower, upper))];
+    let total = |intervals: &[(T, T, Estimate<T>)]| {
+        intervals.iter().fold(
+            Estimate {
+                value: T::zero(),
+                error: T::zero(),
+            },
+            |total, (_, _, e)| Estimate {
+                value: total.value + e.value,
+                error: total.error + e.error,
+            },
+        )
+    };
+    for _ in 0..max_subdivisions {
+        let estimate = total(&intervals);
+        if estimate.error <= tolerance {
+            return Ok(estimate);
+        }
+        let worst = (0..intervals.len())
+            .max_by(|a, b| {
+                intervals[*a]
+                    .2
+                    .error
+                    .partial_cmp(&intervals[*b].2.error)
+                    .unwrap_or(core::cmp::Ordering::Equal)
+            })
+            .unwrap();
+        let (a, b, _) = intervals.swap_remove(worst);
+        let middle = (a + b) / cast(2.);
+        intervals.push((a, middle, kronrod_15(&f, a, middle)));
+        intervals.push((middle, b, kronrod_15(&f, middle, b)));
+    }
+    let estimate = total(&intervals);
+    if estimate.error <= tolerance {
+        Ok(estimate)
+    } else {
+        Err(QuadratureError::NotConverged(estimate))
+    }
+}
+
+/// Intergrates `f` between `lower` and `upper`, either of which may
+/// be infinite, and either of which may be a singularity of `f`.
+///
+/// The range is mapped onto [0, 1], with `x = a + t / (1 - t)` for
+/// infinite bounds, and `x = a + (b - a)(3t^2 - 2t^3)` for finite
+/// ones, which flattens singularities at the ends, then intergrated
+/// with [gauss_kronrod](fn.gauss_kronrod.html). If that doesn't
+/// converge, the contributions of ever smaller slices at each end
+/// are compared, and if they don't shrink the integral is reported
+/// as divergent. So is an infinite sample inside the range, while a
+/// NaN one is reported as
+/// [OutsideDomain](enum.QuadratureError.html#variant.OutsideDomain)
+/// ```
+/// use cakcukus::quadrature::{improper, QuadratureError};
+///
+/// let gaussian = improper(|x: f64| (-x * x).exp(), f64::NEG_INFINITY, f64::INFINITY, 1e-10, 100);
+/// assert!((gaussian.unwrap().value - core::f64::consts::PI.sqrt()).abs() < 1e-10);
+///
+/// let harmonic = improper(|x: f64| 1. / x, 1., f64::INFINITY, 1e-10, 100);
+/// assert_eq!(harmonic, Err(QuadratureError::Divergent));
+/// ```
+pub fn improper<T: Float, F: Fn(T) -> T>(
+    f: F,
+    lower: T,
+    upper: T,
+    tolerance: T,
+    max_subdivisions: usize,
+) -> Result<Estimate<T>, QuadratureError<T>> {
+    improper_by_ref(&f, lower, upper, tolerance, max_subdivisions)
+}
+
+fn improper_by_ref<T: Float, F: Fn(T) -> T>(
+    f: &F,
+    lower: T,
+    upper: T,
+    tolerance: T,
+    max_subdivisions: usize,
+) -> Result<Estimate<T>, QuadratureError<T>> {
+    if lower > upper {
+        return improper_by_ref(f, upper, lower, tolerance, max_subdivisions).map(|e| Estimate {
+            value: -e.value,
+            error: e.error,
+        });
+    }
+    if lower.is_infinite() && upper.is_infinite() {
+        // Splits at 0, so each half has a single infinite bound
+        let half = tolerance / cast(2.);
+        let left = improper_by_ref(f, lower, T::zero(), half, max_subdivisions)?;
+        let right = improper_by_ref(f, T::zero(), upper, half, max_subdivisions)?;
+        return Ok(Estimate {
+            value: left.value + right.value,
+            error: left.error + right.error,
+        });
+    }
+
+    let one = T::one();
+    let failure = Cell::new(None);
+    let transformed = |t: T| {
+        let (x, scale) = if upper.is_infinite() {
+            (lower + t / (one - t), one / ((one - t) * (one - t)))
+        } else if lower.is_infinite() {
+            (upper - (one - t) / t, one / (t * t))
+        } else {
+            let width = upper - lower;
+            let smooth = t * t * (cast::<T, _>(3.) - cast::<T, _>(2.) * t);
+            (lower + width * smooth, width * cast(6.) * t * (one - t))
+        };
+        let value = f(x) * scale;
+        if value.is_finite() {
+            value
+        } else if x == lower || x == upper || !x.is_finite() {
+            // The ends of the range are only reached in the limit, or
+            // by rounding t so close to 0 or 1 that x lands on them
+            T::zero()
+        } else {
+            // Inside the range, an infinite sample means a singularity
+            // the integral can't get past, and a NaN one is outside the
+            // integrand's domain
+            failure.set(Some(if value.is_nan() {
+                QuadratureError::OutsideDomain
+            } else {
+                QuadratureError::Divergent
+            }));
+            T::zero()
+        }
+    };
+
+    let result = match gauss_kronrod(transformed, T::zero(), one, tolerance, max_subdivisions) {
+        Ok(estimate) if estimate.value.is_finite() => Ok(estimate),
+        Ok(_) => Err(QuadratureError::Divergent),
+        Err(QuadratureError::NotConverged(estimate)) => {
+            if shrinking_tail(&transformed, true) && shrinking_tail(&transformed, false) {
+                Err(QuadratureError::NotConverged(estimate))
+            } else {
+                Err(QuadratureError::Divergent)
+            }
+        }
+        Err(error) => Err(error),
+    };
+    match failure.get() {
+        Some(error) => Err(error),
+        None => result,
+    }
+}
+
+/// Intergrates `f` over slices of [0, 1] that halve in width towards
+/// one end, returning false only if their contributions stop
+/// shrinking. Slowly convergent integrals, whose slices shrink by
+/// only a little each halving, are given the benefit of the doubt
+fn shrinking_tail<T: Float, F: Fn(T) -> T>(f: &F, at_upper: bool) -> bool {
+    let mut contributions = Vec::with_capacity(40);
+    let mut width = cast::<T, _>(0.5);
+    for _ in 0..40 {
+        let (a, b) = if at_upper {
+            (T::one() - width, T::one() - width / cast(2.))
+        } else {
+            (width / cast(2.), width)
+        };
+        contributions.push(kronrod_15(f, a, b).value.abs());
+        width = width / cast(2.);
+    }
+    // A convergent integral's slices keep shrinking, while a divergent
+    // one's stay the same or grow. The margin allows for the rounding
+    // of t near 1, which is relatively large for the narrowest slices
+    let last = contributions.len() - 1;
+    contributions[last] < contributions[last - 10] * cast(0.99)
+}
+
+/// Describes a type's ability to be intergrated numerically,
+/// automatically implemented for any type that implements
+/// [TermTrait](../traits/trait.TermTrait.html) over a float
+pub trait NumericalIntegration<T: Float> {
+    /// Intergrates self with the composite trapezoid rule
+    fn integrate_trapezoid(&self, lower: T, upper: T, intervals: usize) -> T;
+    /// Intergrates self with the composite Simpson's rule
+    fn integrate_simpson(&self, lower: T, upper: T, intervals: usize) -> T;
+    /// Intergrates self with Romberg's method
+    fn integrate_romberg(
+        &self,
+        lower: T,
+        upper: T,
+        tolerance: T,
+        max_steps: usize,
+    ) -> Result<Estimate<T>, QuadratureError<T>>;
+    /// Intergrates self with the Gauss–Legendre rule with `order` points
+    fn integrate_gauss_legendre(&self, lower: T, upper: T, order: usize) -> T;
+    /// Intergrates self with adaptive Gauss–Kronrod quadrature
+    fn integrate_adaptive(
+        &self,
+        lower: T,
+        upper: T,
+        tolerance: T,
+        max_subdivisions: usize,
+    ) -> Result<Estimate<T>, QuadratureError<T>>;
+    /// Intergrates self over a range that may be infinite, or end
+    /// at a singularity, with [improper](fn.improper.html)
+    fn integrate_improper_adaptive(
+        &self,
+        lower: T,
+        upper: T,
+        tolerance: T,
+        max_subdivisions: usize,
+    ) -> Result<Estimate<T>, QuadratureError<T>>;
+}
+
+impl<I, T> NumericalIntegration<T> for I
+where
+    I: TermTrait<T>,
+    T: Float + Num + Pow<T, Output = T> + Copy,
+{
+    fn integrate_trapezoid(&self, lower: T, upper: T, intervals: usize) -> T {
+        trapezoid(|x| self.sum_with_respect_to(&x), lower, upper, intervals)
+    }
+    fn integrate_simpson(&self, lower: T, upper: T, intervals: usize) -> T {
+        simpson(|x| self.sum_with_respect_to(&x), lower, upper, intervals)
+    }
+    fn integrate_romberg(
+        &self,
+        lower: T,
+        upper: T,
+        tolerance: T,
+        max_steps: usize,
+    ) -> Result<Estimate<T>, QuadratureError<T>> {
+        romberg(
+            |x| self.sum_with_respect_to(&x),
+            lower,
+            upper,
+            tolerance,
+            max_steps,
+        )
+    }
+    fn integrate_gauss_legendre(&self, lower: T, upper: T, order: usize) -> T {
+        gauss_legendre(|x| self.sum_with_respect_to(&x), lower, upper, order)
+    }
+    fn integrate_adaptive(
+        &self,
+        lower: T,
+        upper: T,
+        tolerance: T,
+        max_subdivisions: usize,
+    ) -> Result<Estimate<T>, QuadratureError<T>> {
+        gauss_kronrod(
+            |x| self.sum_with_respect_to(&x),
+            lower,
+            upper,
+            tolerance,
+            max_subdivisions,
+        )
+    }
+    fn integrate_improper_adaptive(
+        &self,
+        lower: T,
+        upper: T,
+        tolerance: T,
+        max_subdivisions: usize,
+    ) -> Result<Estimate<T>, QuadratureError<T>> {
+        improper(
+            |x| self.sum_with_respect_to(&x),
+            lower,
+            upper,
+            tolerance,
+            max_subdivisions,
+        )
+    }
+}
@@ -0,0 +1,99 @@
+use crate::{
+    traits::{Differentiation, TermTrait},
+    Polynomial,
+};
+
+use num_traits::{
+    identities::{one, zero},
+    Num, Pow,
+};
+
+/// A RationalFunction is the ratio of two polynomials,
+/// `numerator(x) / denominator(x)`
+#[derive(Debug, Clone, PartialEq)]
+pub struct RationalFunction<T>
+where
+    T: Num + Pow<T, Output = T> + Clone + PartialOrd,
+{
+    /// The polynomial above the line
+    pub numerator: Polynomial<T>,
+
+    /// The polynomial below the line
+    pub denominator: Polynomial<T>,
+}
+
+impl<T> RationalFunction<T>
+where
+    T: Num + Pow<T, Output = T> + Clone + PartialOrd,
+{
+    /// Constructs a new rational function based off a given
+    /// numerator and denominator
+    pub fn new(numerator: Polynomial<T>, denominator: Polynomial<T>) -> Self {
+        Self {
+            numerator,
+            denominator,
+        }
+    }
+    /// Simplifies both the numerator and denominator
+    pub fn simplify(&mut self) {
+        self.numerator.simplify();
+        self.denominator.simplify();
+    }
+}
+
+impl<T> From<Polynomial<T>> for RationalFunction<T>
+where
+    T: Num + Pow<T, Output = T> + Clone + PartialOrd,
+{
+    fn from(polynomial: Polynomial<T>) -> Self {
+        Self::new(
+            polynomial,
+            Polynomial(vec![crate::Term::new(one(), zero())]),
+        )
+    }
+}
+
+impl<T> TermTrait<T> for RationalFunction<T>
+where
+    T: Num + Pow<T, Output = T> + Copy + PartialOrd,
+{
+    fn sum_with_respect_to(&self, x: &T) -> T {
+        self.numerator.sum_with_respect_to(x) / self.denominator.sum_with_respect_to(x)
+    }
+}
+
+impl<T> Differentiation<T> for RationalFunction<T>
+where
+    T: Num + Pow<T, Output = T> + Copy + PartialOrd,
+{
+    /// Uses the quotient rule, `(n'd - nd') / d^2`
+    fn differentiate(&self, x: &T) -> T {
+        let d = self.denominator.sum_with_respect_to(x);
+        (self.numerator.differentiate(x) * d
+            - self.numerator.sum_with_respect_to(x) * self.denominator.differentiate(x))
+            / (d * d)
+    }
+    fn differentiate_self(&self) -> Self {
+        let mut numerator = self.numerator.differentiate_self() * self.denominator.clone()
+            + self.numerator.clone()
+                * self.denominator.differentiate_self()
+                * (zero::<T>() - one());
+        numerator.simplify();
+        let mut denominator = self.denominator.clone() * self.denominator.clone();
+        denominator.simplify();
+        Self::new(numerator, denominator)
+    }
+}
+
+mod impl_std_traits {
+    use super::RationalFunction;
+    use core::fmt::Display;
+
+    use num_traits::{Num, Pow};
+
+    impl<T: Num + Pow<T, Output = T> + Clone + PartialOrd + Display> Display for RationalFunction<T> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "({}) / ({})", self.numerator, self.denominator)
+        }
+    }
+}
@@ -0,0 +1,245 @@
+//! The Remez exchange algorithm, for the polynomial of a given degree
+//! with the smallest largest error on an interval, along with Rust
+//! source generation for evaluating it.
+
+use core::fmt::Display;
+
+use crate::{chebyshev::Chebyshev, linalg, traits::TermTrait, Polynomial};
+
+/// The most exchanges made before giving up
+const MAX_ITERATIONS: usize = 64;
+
+/// How close the errors at the reference points must be, relative to
+/// their size, for the error to count as levelled
+const LEVELLED: f64 = 1e-9;
+
+/// The number of points sampled when looking for each extremum
+const SAMPLES: usize = 32;
+
+/// The reasons a minimax approximation couldn't be found
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RemezError {
+    /// The linear system at a reference was singular
+    Singular,
+    /// The error didn't level out within the iteration limit, as can
+    /// happen for functions that aren't continuous
+    NotConverged,
+}
+
+impl Display for RemezError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RemezError::Singular => write!(f, "the reference system was singular"),
+            RemezError::NotConverged => write!(f, "the error did not equioscillate"),
+        }
+    }
+}
+
+impl std::error::Error for RemezError {}
+
+/// The best uniform approximation of a function by a polynomial
+#[derive(Debug, Clone, PartialEq)]
+pub struct Minimax {
+    /// The approximating polynomial
+    pub polynomial: Polynomial<f64>,
+
+    /// The largest absolute error on the interval, which is reached,
+    /// with alternating signs, at each reference point
+    pub error: f64,
+
+    /// The `degree + 2` points the error equioscillates at
+    pub reference: Vec<f64>,
+}
+
+/// Returns the polynomial of at most `degree` minimising the largest
+/// value of `|f(x) - p(x)|` on `[lower, upper]`
+/// ```
+/// use cakcukus::{remez::remez, traits::TermTrait};
+///
+/// let minimax = remez(|x| x.exp(), -1., 1., 4).unwrap();
+///
+/// for i in 0..=20 {
+///     let x = -1. + 0.1 * i as f64;
+///     let error = (minimax.polynomial.sum_with_respect_to(&x) - x.exp()).abs();
+///     assert!(error <= minimax.error * (1. + 1e-6));
+/// }
+/// ```
+pub fn remez<F: Fn(f64) -> f64>(
+    f: F,
+    lower: f64,
+    upper: f64,
+    degree: usize,
+) -> Result<Minimax, RemezError> {
+    let n = degree + 2;
+    let centre = (lower + upper) / 2.;
+    let half_width = (upper - lower) / 2.;
+    // Starting from the extrema of the Chebyshev polynomial of
+    // degree n - 1, which is already close to optimal
+    let mut reference: Vec<f64> = (0..n)
+        .map(|i| centre - half_width * (core::f64::consts::PI * i as f64 / (n - 1) as f64).cos())
+        .collect();
+
+    for _ in 0..MAX_ITERATIONS {
+        // Solve p(xi) + (-1)^i E = f(xi), with p in the Chebyshev basis
+        let mut matrix = Vec::with_capacity(n);
+        let mut rhs = Vec::with_capacity(n);
+        for (i, x) in reference.iter().enumerate() {
+            let t = (x - centre) / half_width;
+            let mut row: Vec<f64> = (0..=degree)
+                .map(|j| (j as f64 * t.clamp(-1., 1.).acos()).cos())
+                .collect();
+            row.push(if i % 2 == 0 { 1. } else { -1. });
+            matrix.push(row);
+            rhs.push(f(*x));
+        }
+        let mut solution = linalg::solve(matrix, rhs).ok_or(RemezError::Singular)?;
+        let levelled = solution.pop().unwrap().abs();
+        let approximation = Chebyshev::from_coefficients(solution, lower, upper);
+        let error = |x: f64| f(x) - approximation.sum_with_respect_to(&x);
+
+        // The error changes sign between each pair of reference
+        // points, so has a root there
+        let mut bounds = vec![lower];
+        for pair in reference.windows(2) {
+            bounds.push(bisect(&error, pair[0], pair[1]));
+        }
+        bounds.push(upper);
+
+        // The new reference holds the extremum between each pair of
+        // roots, keeping the alternating signs
+        let mut next = Vec::with_capacity(n);
+        for (i, window) in bounds.windows(2).enumerate() {
+            let sign = error(reference[i]).signum();
+            next.push(extremum(|x| sign * error(x), window[0], window[1]));
+        }
+        let errors: Vec<f64> = next.iter().map(|x| error(*x).abs()).collect();
+        let largest = errors.iter().fold(0f64, |max, e| max.max(*e));
+        let smallest = errors.iter().fold(f64::INFINITY, |min, e| min.min(*e));
+        reference = next;
+
+        let scale = reference.iter().fold(0f64, |max, x| max.max(f(*x).abs()));
+        if largest <= f64::EPSILON * scale.max(1.) * 16. || largest - smallest <= LEVELLED * largest
+        {
+            return Ok(Minimax {
+                polynomial: approximation.to_polynomial(),
+                error: largest.max(levelled),
+                reference,
+            });
+        }
+    }
+    Err(RemezError::NotConverged)
+}
+
+/// Finds a root of `f` between `a` and `b`, where it changes sign
+fn bisect<F: Fn(f64) -> f64>(f: &F, mut a: f64, mut b: f64) -> f64 {
+    let mut fa = f(a);
+    for _ in 0..128 {
+        let middle = (a + b) / 2.;
+        if middle <= a || middle >= b {
+            break;
+        }
+        let fm = f(middle);
+        if (fm < 0.) == (fa < 0.) {
+            a = middle;
+            fa = fm;
+        } else {
+            b = middle;
+        }
+    }
+    (a + b) / 2.
+}
+
+/// Finds where `f` is largest on `[a, b]`, by sampling and then
+/// refining around the best sample with a golden section search
+fn extremum<F: Fn(f64) -> f64>(f: F, a: f64, b: f64) -> f64 {
+    let step = (b - a) / SAMPLES as f64;
+    let best = (0..=SAMPLES)
+        .map(|i| a + step * i as f64)
+        .max_by(|x, y| {
+            f(*x)
+                .partial_cmp(&f(*y))
+                .unwrap_or(core::cmp::Ordering::Equal)
+        })
+        .unwrap_or(a);
+
+    let ratio = (5f64.sqrt() - 1.) / 2.;
+    let (mut left, mut right) = ((best - step).max(a), (best + step).min(b));
+    for _ in 0..128 {
+        if right - left <= f64::EPSILON * (left.abs() + right.abs()) {
+            break;
+        }
+        let x1 = right - ratio * (right - left);
+        let x2 = left + ratio * (right - left);
+        if f(x1) < f(x2) {
+            left = x1;
+        } else {
+            right = x2;
+        }
+    }
+    let refined = (left + right) / 2.;
+    // The ends of the interval aren't reached by the search
+    [a, b, refined]
+        .iter()
+        .copied()
+        .max_by(|x, y| {
+            f(*x)
+                .partial_cmp(&f(*y))
+                .unwrap_or(core::cmp::Ordering::Equal)
+        })
+        .unwrap()
+}
+
+/// Returns `value` as Rust source, using the named constants for the
+/// values that have no literal
+fn literal(value: f64) -> String {
+    if value.is_nan() {
+        "f64::NAN".to_string()
+    } else if value == f64::INFINITY {
+        "f64::INFINITY".to_string()
+    } else if value == f64::NEG_INFINITY {
+        "f64::NEG_INFINITY".to_string()
+    } else {
+        format!("{:?}", value)
+    }
+}
+
+impl Minimax {
+    /// Returns Rust source for a function called `name`, evaluating
+    /// the polynomial with Horner's method
+    /// ```
+    /// use cakcukus::remez::remez;
+    ///
+    /// let line = remez(|x| 2. * x + 1., 0., 1., 1).unwrap();
+    /// let source = line.to_rust("line");
+    ///
+    /// assert!(source.contains("pub fn line(x: f64) -> f64 {"));
+    /// ```
+    pub fn to_rust(&self, name: &str) -> String {
+        let coefficients = self.polynomial.dense_coefficients().unwrap_or_default();
+        let mut source = format!(
+            "/// Minimax approximation, with a largest error of {:e}\npub fn {}(x: f64) -> f64 {{\n",
+            self.error, name
+        );
+        let mut highest_first = coefficients.iter().rev();
+        match highest_first.next() {
+            Some(constant) if coefficients.len() == 1 => {
+                source += &format!("    {}\n", literal(*constant));
+            }
+            Some(leading) => {
+                source += &format!("    let mut y = {};\n", literal(*leading));
+                for coefficient in highest_first {
+                    let sign = if coefficient.is_sign_negative() {
+                        '-'
+                    } else {
+                        '+'
+                    };
+                    source += &format!("    y = y * x {} {};\n", sign, literal(coefficient.abs()));
+                }
+                source += "    y\n";
+            }
+            None => source += "    0.0\n",
+        }
+        source += "}\n";
+        source
+    }
+}
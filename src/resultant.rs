@@ -0,0 +1,228 @@
+//! Resultants and discriminants, which answer whether two polynomials
+//! share a root, or one has a repeated root, without finding any
+//! roots. Resultants are found by the subresultant pseudo-remainder
+//! sequence, whose divisions are all exact, so with
+//! [Rational](../exact/struct.Rational.html) coefficients the result
+//! is exact and the intermediate coefficients stay small.
+
+use num_traits::{
+    identities::{one, zero},
+    Num, Pow,
+};
+
+use crate::{util::from_u32, Polynomial};
+
+/// Returns the ascending coefficients of `polynomial`, without any
+/// trailing zeros, so that the zero polynomial has none
+///
+/// # Panics
+/// If any exponent isn't a whole number of at least 0
+fn coefficients<T>(polynomial: &Polynomial<T>) -> Vec<T>
+where
+    T: Num + Pow<T, Output = T> + Clone + PartialOrd,
+{
+    let mut coefficients = polynomial
+        .dense_coefficients()
+        .expect("Only whole, non-negative exponents have a resultant");
+    trim(&mut coefficients);
+    coefficients
+}
+
+/// Drops any zero leading coefficients
+fn trim<T: Num>(coefficients: &mut Vec<T>) {
+    while coefficients.last().is_some_and(|c| c.is_zero()) {
+        coefficients.pop();
+    }
+}
+
+/// Returns `x^n`, for a whole `n`
+fn power<T: Num + Clone>(x: &T, n: usize) -> T {
+    (0..n).fold(one(), |total: T, _| total * x.clone())
+}
+
+/// Returns the remainder of `lc(b)^(deg a - deg b + 1) a` divided by
+/// `b`, which needs no division, where `deg a >= deg b >= 0`
+fn pseudo_remainder<T: Num + Clone>(a: &[T], b: &[T]) -> Vec<T> {
+    let leading = b[b.len() - 1].clone();
+    let mut remainder = a.to_vec();
+    let mut steps = a.len() - b.len() + 1;
+    while remainder.len() >= b.len() {
+        let top = remainder.pop().unwrap();
+        let offset = remainder.len() + 1 - b.len();
+        for c in remainder.iter_mut() {
+            *c = c.clone() * leading.clone();
+        }
+        for (i, c) in b.iter().take(b.len() - 1).enumerate() {
+            remainder[offset + i] = remainder[offset + i].clone() - top.clone() * c.clone();
+        }
+        steps -= 1;
+        trim(&mut remainder);
+    }
+    // Steps skipped by cancellation still count towards the power
+    let scale = power(&leading, steps);
+    remainder.into_iter().map(|c| c * scale.clone()).collect()
+}
+
+/// Returns the Sylvester matrix of `p` and `q`, of degrees m and n,
+/// whose first n rows hold the coefficients of p, highest first, and
+/// last m rows those of q, each row shifted one further right. Its
+/// determinant is the resultant
+///
+/// # Panics
+/// If any exponent isn't a whole number of at least 0
+/// ```
+/// use cakcukus::{resultant::sylvester_matrix, terms, Polynomial};
+///
+/// let p: Polynomial<f64> = terms!(1., 2., -1., 0.);
+/// let q: Polynomial<f64> = terms!(2., 1., 3., 0.);
+///
+/// assert_eq!(
+///     sylvester_matrix(&p, &q),
+///     vec![vec![1., 0., -1.], vec![2., 3., 0.], vec![0., 2., 3.]]
+/// );
+/// ```
+pub fn sylvester_matrix<T>(p: &Polynomial<T>, q: &Polynomial<T>) -> Vec<Vec<T>>
+where
+    T: Num + Pow<T, Output = T> + Clone + PartialOrd,
+{
+    let (p, q) = (coefficients(p), coefficients(q));
+    let m = p.len().saturating_sub(1);
+    let n = q.len().saturating_sub(1);
+    let size = m + n;
+    let mut matrix = Vec::with_capacity(size);
+    for (coefficients, shifts) in [(&p, n), (&q, m)].iter() {
+        for shift in 0..*shifts {
+            let mut row = vec![zero(); size];
+            for (i, c) in coefficients.iter().rev().enumerate() {
+                row[shift + i] = c.clone();
+            }
+            matrix.push(row);
+        }
+    }
+    matrix
+}
+
+/// Returns the resultant of `p` and `q`, which is 0 exactly when they
+/// share a root, or either is 0
+///
+/// # Panics
+/// If any exponent isn't a whole number of at least 0
+/// ```
+/// use cakcukus::{exact::Rational, resultant::resultant, terms, Polynomial};
+///
+/// let r = Rational::from_integer;
+/// // x^2 - 1 and x^2 - 3x + 2 share the root 1
+/// let p = terms!(r(1), r(2), r(-1), r(0));
+/// let q = Polynomial(vec![terms!(r(1), r(2)), terms!(r(-3), r(1)), terms!(r(2), r(0))]);
+///
+/// assert_eq!(resultant(&p, &q), r(0));
+/// assert_eq!(resultant(&p, &terms!(r(1), r(1), r(-2), r(0))), r(3));
+/// ```
+pub fn resultant<T>(p: &Polynomial<T>, q: &Polynomial<T>) -> T
+where
+    T: Num + Pow<T, Output = T> + Clone + PartialOrd,
+{
+    let (mut a, mut b) = (coefficients(p), coefficients(q));
+    if a.is_empty() || b.is_empty() {
+        return zero();
+    }
+    let mut sign_flipped = false;
+    if a.len() < b.len() {
+        // res(q, p) = (-1)^(deg p deg q) res(p, q)
+        sign_flipped = (a.len() - 1) % 2 == 1 && (b.len() - 1) % 2 == 1;
+        core::mem::swap(&mut a, &mut b);
+    }
+
+    let (mut g, mut h): (T, T) = (one(), one());
+    while b.len() > 1 {
+        let (degree_a, degree_b) = (a.len() - 1, b.len() - 1);
+        let delta = degree_a - degree_b;
+        if degree_a % 2 == 1 && degree_b % 2 == 1 {
+            sign_flipped = !sign_flipped;
+        }
+        let remainder = pseudo_remainder(&a, &b);
+        if remainder.is_empty() {
+            return zero();
+        }
+        // Every coefficient of the remainder is divisible by g h^delta
+        let divisor = g.clone() * power(&h, delta);
+        a = b;
+        b = remainder.into_iter().map(|c| c / divisor.clone()).collect();
+        g = a[a.len() - 1].clone();
+        // h = g^delta / h^(delta - 1)
+        h = if delta == 0 {
+            h
+        } else {
+            power(&g, delta) / power(&h, delta - 1)
+        };
+    }
+
+    // b is now a non-zero constant
+    let degree_a = a.len() - 1;
+    let constant = b[0].clone();
+    let result = power(&constant, degree_a) / power(&h, degree_a.saturating_sub(1));
+    if sign_flipped {
+        zero::<T>() - result
+    } else {
+        result
+    }
+}
+
+/// Returns the discriminant of `p`, which is 0 exactly when it has a
+/// repeated root. For `ax^2 + bx + c`, this is `b^2 - 4ac`
+///
+/// # Panics
+/// If any exponent isn't a whole number of at least 0, or `p` is a
+/// constant
+/// ```
+/// use cakcukus::{exact::Rational, resultant::discriminant, terms, Polynomial};
+///
+/// let r = Rational::from_integer;
+/// // x^3 - 3x + 2 = (x - 1)^2 (x + 2)
+/// let p = Polynomial(vec![terms!(r(1), r(3)), terms!(r(-3), r(1)), terms!(r(2), r(0))]);
+///
+/// assert_eq!(discriminant(&p), r(0));
+/// ```
+pub fn discriminant<T>(p: &Polynomial<T>) -> T
+where
+    T: Num + Pow<T, Output = T> + Clone + PartialOrd,
+{
+    let a = coefficients(p);
+    assert!(
+        a.len() >= 2,
+        "Only non-constant polynomials have a discriminant"
+    );
+    let n = a.len() - 1;
+    let derivative: Vec<T> = a
+        .iter()
+        .enumerate()
+        .skip(1)
+        .map(|(i, c)| c.clone() * from_u32(i as u32))
+        .collect();
+    // disc(p) = (-1)^(n(n - 1) / 2) res(p, p') / lc(p)
+    let result = resultant(
+        &Polynomial::from_coefficients(&a),
+        &Polynomial::from_coefficients(&derivative),
+    ) / a[n].clone();
+    if (n * (n - 1) / 2) % 2 == 1 {
+        zero::<T>() - result
+    } else {
+        result
+    }
+}
+
+impl<T> Polynomial<T>
+where
+    T: Num + Pow<T, Output = T> + Clone + PartialOrd,
+{
+    /// Returns the resultant of self and `other`, as in
+    /// [resultant](resultant/fn.resultant.html)
+    pub fn resultant(&self, other: &Self) -> T {
+        resultant(self, other)
+    }
+    /// Returns the discriminant of self, as in
+    /// [discriminant](resultant/fn.discriminant.html)
+    pub fn discriminant(&self) -> T {
+        discriminant(self)
+    }
+}
@@ -0,0 +1,167 @@
+//! Finding every root of a polynomial at once, as the eigenvalues of
+//! its companion matrix. The eigenvalue solver needs the `nalgebra`
+//! feature, while the companion matrix itself is always available.
+
+use num_traits::{identities::zero, Num, Pow};
+
+use crate::Polynomial;
+
+#[cfg(feature = "nalgebra")]
+use core::fmt::Display;
+
+#[cfg(feature = "nalgebra")]
+use nalgebra::{linalg::balancing::balance_parlett_reinsch, Complex, DMatrix, RealField, Schur};
+
+impl<T> Polynomial<T>
+where
+    T: Num + Pow<T, Output = T> + Clone + PartialOrd,
+{
+    /// Returns the companion matrix of the polynomial, whose
+    /// eigenvalues are its roots. For `x^n + a(n-1)x^(n-1) + ... + a0`,
+    /// after dividing through by the leading coefficient, this has 1s
+    /// below the diagonal and `-a0, -a1, ..., -a(n-1)` down the last
+    /// column
+    ///
+    /// # Panics
+    /// If any exponent isn't a whole number of at least 0, or the
+    /// polynomial is a constant
+    /// ```
+    /// use cakcukus::{terms, Polynomial};
+    ///
+    /// // 2x^2 - 6x + 4
+    /// let p: Polynomial<f64> = Polynomial(vec![terms!(2., 2.), terms!(-6., 1.), terms!(4., 0.)]);
+    ///
+    /// assert_eq!(p.companion_matrix(), vec![vec![0., -2.], vec![1., 3.]]);
+    /// ```
+    pub fn companion_matrix(&self) -> Vec<Vec<T>> {
+        let mut coefficients = self
+            .dense_coefficients()
+            .expect("Only whole, non-negative exponents have a companion matrix");
+        while coefficients.last().is_some_and(|c| c.is_zero()) {
+            coefficients.pop();
+        }
+        assert!(
+            coefficients.len() >= 2,
+            "Only non-constant polynomials have a companion matrix"
+        );
+        let leading = coefficients.pop().unwrap();
+        let n = coefficients.len();
+        let mut matrix = vec![vec![zero(); n]; n];
+        for (i, (row, c)) in matrix.iter_mut().zip(coefficients).enumerate() {
+            if i > 0 {
+                row[i - 1] = T::one();
+            }
+            row[n - 1] = zero::<T>() - c / leading.clone();
+        }
+        matrix
+    }
+}
+
+/// The reasons the roots of a polynomial couldn't be found
+#[cfg(feature = "nalgebra")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RootsError {
+    /// The eigenvalue iteration didn't converge
+    NotConverged,
+}
+
+#[cfg(feature = "nalgebra")]
+impl Display for RootsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RootsError::NotConverged => write!(f, "the eigenvalues did not converge"),
+        }
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl std::error::Error for RootsError {}
+
+/// The most QR iterations allowed per root
+#[cfg(feature = "nalgebra")]
+const ITERATIONS_PER_ROOT: usize = 64;
+
+/// The shifts tried, relative to the largest entry of the balanced
+/// companion matrix, when the QR iteration stalls. Roots in `+-`
+/// pairs, as even polynomials have, can stop it from converging,
+/// which shifting every root by the same amount avoids
+#[cfg(feature = "nalgebra")]
+const RETRY_SHIFTS: [f64; 3] = [0., 1e-3, 1e-1];
+
+#[cfg(feature = "nalgebra")]
+impl<T> Polynomial<T>
+where
+    T: RealField + Num + Pow<T, Output = T> + Clone + PartialOrd,
+{
+    /// Returns every root of the polynomial, repeated by multiplicity
+    /// and sorted by real then imaginary part, as the eigenvalues of
+    /// its companion matrix. The matrix is balanced first, which
+    /// rescales rows and columns to similar sizes, as otherwise
+    /// coefficients of very different sizes cost a lot of accuracy.
+    /// Roots at 0 are factored out exactly beforehand, and if the
+    /// iteration stalls, it's retried with every root shifted by a
+    /// small amount
+    ///
+    /// # Panics
+    /// If any exponent isn't a whole number of at least 0, or the
+    /// polynomial is 0
+    /// ```
+    /// use cakcukus::{terms, Polynomial};
+    ///
+    /// // x^3 - x = x(x - 1)(x + 1)
+    /// let p: Polynomial<f64> = terms!(1., 3., -1., 1.);
+    /// let roots = p.roots().unwrap();
+    ///
+    /// for (root, expected) in roots.iter().zip([-1., 0., 1.].iter()) {
+    ///     assert!((root.re - expected).abs() < 1e-12);
+    ///     assert!(root.im.abs() < 1e-12);
+    /// }
+    /// ```
+    pub fn roots(&self) -> Result<Vec<Complex<T>>, RootsError> {
+        let coefficients = self
+            .dense_coefficients()
+            .expect("Only whole, non-negative exponents have roots");
+        assert!(
+            coefficients.iter().any(|c| !c.is_zero()),
+            "Every number is a root of 0"
+        );
+        let zeros = coefficients.iter().take_while(|c| c.is_zero()).count();
+        let mut roots = vec![Complex::new(zero(), zero()); zeros];
+
+        let rest = Polynomial::from_coefficients(&coefficients[zeros..]);
+        let n = coefficients.len() - zeros - 1;
+        if n > 0 {
+            let companion = rest.companion_matrix();
+            let mut matrix = DMatrix::from_fn(n, n, |i, j| companion[i][j].clone());
+            // Balancing is a similarity transform, so keeps the eigenvalues
+            balance_parlett_reinsch(&mut matrix);
+            let scale = matrix.amax();
+            let eigenvalues = RETRY_SHIFTS
+                .iter()
+                .find_map(|relative| {
+                    let shift: T = nalgebra::convert::<f64, T>(*relative) * scale.clone();
+                    let shifted = &matrix + DMatrix::identity(n, n) * shift.clone();
+                    let schur = Schur::try_new(
+                        shifted,
+                        nalgebra::convert(f64::EPSILON),
+                        ITERATIONS_PER_ROOT * n,
+                    )?;
+                    let eigenvalues = schur.complex_eigenvalues();
+                    Some(
+                        eigenvalues
+                            .iter()
+                            .map(|z| Complex::new(z.re.clone() - shift.clone(), z.im.clone()))
+                            .collect::<Vec<_>>(),
+                    )
+                })
+                .ok_or(RootsError::NotConverged)?;
+            roots.extend(eigenvalues);
+        }
+        roots.sort_by(|a, b| {
+            (a.re.clone(), a.im.clone())
+                .partial_cmp(&(b.re.clone(), b.im.clone()))
+                .unwrap_or(core::cmp::Ordering::Equal)
+        });
+        Ok(roots)
+    }
+}
@@ -0,0 +1,380 @@
+//! Piecewise polynomial functions joined at knots, along with
+//! interpolating splines, either cubic with a choice of boundary
+//! condition, or of any degree from B-splines.
+
+use num_traits::{Float, Pow};
+
+use crate::{
+    linalg,
+    traits::{Differentiation, Integration, TermTrait},
+    Polynomial, Term,
+};
+
+/// What a cubic spline does at its two ends, which fixes the two
+/// degrees of freedom the interior conditions leave
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Boundary<T> {
+    /// The second derivative is 0 at both ends
+    Natural,
+    /// The first derivative is given at the start and end
+    Clamped(T, T),
+    /// The third derivative is continuous at the second and second
+    /// to last knots, so the first two and last two pieces are each
+    /// the same cubic
+    NotAKnot,
+}
+
+/// A function made of polynomial pieces, with the ith piece used
+/// between the ith and (i + 1)th knot. Each piece is in powers of
+/// `(x - knot)`, measured from the start of its interval, which keeps
+/// them well conditioned far from 0. Outside of the knots, the first
+/// and last pieces are extended
+#[derive(Debug, Clone, PartialEq)]
+pub struct Spline<T>
+where
+    T: Float + Pow<T, Output = T>,
+{
+    knots: Vec<T>,
+    pieces: Vec<Polynomial<T>>,
+}
+
+/// Solves a tridiagonal system by the Thomas algorithm, where
+/// `sub[i]` and `sup[i]` are left and right of `diagonal[i]`
+fn solve_tridiagonal<T: Float>(sub: &[T], diagonal: &[T], sup: &[T], rhs: &[T]) -> Vec<T> {
+    let n = diagonal.len();
+    let mut sup_prime = vec![T::zero(); n];
+    let mut rhs_prime = vec![T::zero(); n];
+    for i in 0..n {
+        let (previous_sup, previous_rhs) = if i == 0 {
+            (T::zero(), T::zero())
+        } else {
+            (sup_prime[i - 1], rhs_prime[i - 1])
+        };
+        let pivot = diagonal[i] - sub[i] * previous_sup;
+        sup_prime[i] = sup[i] / pivot;
+        rhs_prime[i] = (rhs[i] - sub[i] * previous_rhs) / pivot;
+    }
+    let mut solution = vec![T::zero(); n];
+    for i in (0..n).rev() {
+        let next = if i + 1 < n {
+            solution[i + 1]
+        } else {
+            T::zero()
+        };
+        solution[i] = rhs_prime[i] - sup_prime[i] * next;
+    }
+    solution
+}
+
+/// Adds `(offset + u) / scale` times `polynomial` to `total`, both
+/// being dense coefficients in powers of u
+fn add_linear_multiple<T: Float>(total: &mut [T], polynomial: &[T], offset: T, scale: T) {
+    for (i, c) in polynomial.iter().enumerate() {
+        total[i] = total[i] + offset * *c / scale;
+        total[i + 1] = total[i + 1] + *c / scale;
+    }
+}
+
+/// Returns the B-splines of `degree` over `knots` that are non-zero
+/// on `[knots[span], knots[span + 1])`, as dense coefficients in
+/// powers of `(x - knots[span])`, by the Cox–de Boor recursion. The
+/// rth belongs to the B-spline starting at `knots[span - degree + r]`
+fn basis_on_span<T: Float>(knots: &[T], span: usize, degree: usize) -> Vec<Vec<T>> {
+    let start = knots[span];
+    let mut basis = vec![vec![T::one()]];
+    for p in 1..=degree {
+        let mut next = vec![vec![T::zero(); p + 1]; p + 1];
+        for (r, total) in next.iter_mut().enumerate() {
+            // B(j, p) = (x - t(j)) / (t(j + p) - t(j)) B(j, p - 1)
+            //     + (t(j + p + 1) - x) / (t(j + p + 1) - t(j + 1)) B(j + 1, p - 1)
+            let j = span + r - p;
+            if r > 0 {
+                let width = knots[j + p] - knots[j];
+                if !width.is_zero() {
+                    add_linear_multiple(total, &basis[r - 1], start - knots[j], width);
+                }
+            }
+            if r < p {
+                let width = knots[j + p + 1] - knots[j + 1];
+                if !width.is_zero() {
+                    add_linear_multiple(total, &basis[r], start - knots[j + p + 1], -width);
+                }
+            }
+        }
+        basis = next;
+    }
+    basis
+}
+
+impl<T> Spline<T>
+where
+    T: Float + Pow<T, Output = T>,
+{
+    /// Constructs a new spline from its knots and the pieces between
+    /// them, each in powers of `(x - knot)`
+    ///
+    /// # Panics
+    /// If there are fewer than two knots, there isn't exactly one
+    /// fewer piece than knots, or the knots aren't strictly increasing
+    pub fn new(knots: Vec<T>, pieces: Vec<Polynomial<T>>) -> Self {
+        assert!(knots.len() >= 2, "A spline needs at least two knots");
+        assert!(
+            knots.len() == pieces.len() + 1,
+            "A spline needs one more knot than pieces"
+        );
+        assert!(
+            knots.windows(2).all(|pair| pair[0] < pair[1]),
+            "Spline knots must be strictly increasing"
+        );
+        Self { knots, pieces }
+    }
+    /// Builds the cubic spline through the points `(xs, ys)`, which
+    /// has continuous first and second derivatives
+    ///
+    /// # Panics
+    /// If there are fewer than two points, `xs` and `ys` have
+    /// different lengths, or `xs` isn't strictly increasing
+    /// ```
+    /// use cakcukus::{spline::Boundary, traits::TermTrait, Spline};
+    ///
+    /// let spline = Spline::cubic(&[0f64, 1., 2., 3.], &[0., 1., 8., 27.], Boundary::NotAKnot);
+    ///
+    /// // Not-a-knot reproduces cubics exactly
+    /// assert!((spline.sum_with_respect_to(&1.5) - 3.375).abs() < 1e-12);
+    /// ```
+    pub fn cubic(xs: &[T], ys: &[T], boundary: Boundary<T>) -> Self {
+        assert!(xs.len() == ys.len(), "Every x needs a y");
+        assert!(xs.len() >= 2, "A spline needs at least two points");
+        let n = xs.len();
+        let two = T::one() + T::one();
+        let six = two + two + two;
+        let h: Vec<T> = xs.windows(2).map(|pair| pair[1] - pair[0]).collect();
+        let slopes: Vec<T> = (0..n - 1).map(|i| (ys[i + 1] - ys[i]) / h[i]).collect();
+
+        // The second derivatives at each knot satisfy
+        // h(i-1) M(i-1) + 2(h(i-1) + h(i)) M(i) + h(i) M(i+1) = 6(d(i) - d(i-1))
+        let mut sub = vec![T::zero(); n];
+        let mut diagonal = vec![T::one(); n];
+        let mut sup = vec![T::zero(); n];
+        let mut rhs = vec![T::zero(); n];
+        for i in 1..n - 1 {
+            sub[i] = h[i - 1];
+            diagonal[i] = two * (h[i - 1] + h[i]);
+            sup[i] = h[i];
+            rhs[i] = six * (slopes[i] - slopes[i - 1]);
+        }
+
+        let second_derivatives = match boundary {
+            Boundary::Natural => solve_tridiagonal(&sub, &diagonal, &sup, &rhs),
+            Boundary::Clamped(start, end) => {
+                diagonal[0] = two * h[0];
+                sup[0] = h[0];
+                rhs[0] = six * (slopes[0] - start);
+                sub[n - 1] = h[n - 2];
+                diagonal[n - 1] = two * h[n - 2];
+                rhs[n - 1] = six * (end - slopes[n - 2]);
+                solve_tridiagonal(&sub, &diagonal, &sup, &rhs)
+            }
+            // With three points, every piece is the parabola through
+            // them, and with two, the line
+            Boundary::NotAKnot if n == 3 => {
+                vec![two * (slopes[1] - slopes[0]) / (xs[2] - xs[0]); 3]
+            }
+            Boundary::NotAKnot if n == 2 => vec![T::zero(); 2],
+            Boundary::NotAKnot => {
+                // M0 and M(n-1) follow from their neighbours, so are
+                // substituted out of the first and last interior rows
+                let (h0, h1) = (h[0], h[1]);
+                diagonal[1] = (h0 + h1) * (h0 + two * h1) / h1;
+                sup[1] = (h1 * h1 - h0 * h0) / h1;
+                let (ha, hb) = (h[n - 3], h[n - 2]);
+                sub[n - 2] = (ha * ha - hb * hb) / ha;
+                diagonal[n - 2] = (ha + hb) * (two * ha + hb) / ha;
+
+                sub[1] = T::zero();
+                sup[n - 2] = T::zero();
+                let mut interior = solve_tridiagonal(
+                    &sub[1..n - 1],
+                    &diagonal[1..n - 1],
+                    &sup[1..n - 1],
+                    &rhs[1..n - 1],
+                );
+                let first = ((h0 + h1) * interior[0] - h0 * interior[1]) / h1;
+                let last = ((ha + hb) * interior[n - 3] - hb * interior[n - 4]) / ha;
+                interior.insert(0, first);
+                interior.push(last);
+                interior
+            }
+        };
+
+        let pieces = (0..n - 1)
+            .map(|i| {
+                let (m0, m1) = (second_derivatives[i], second_derivatives[i + 1]);
+                Polynomial::from_coefficients(&[
+                    ys[i],
+                    slopes[i] - h[i] * (two * m0 + m1) / six,
+                    m0 / two,
+                    (m1 - m0) / (six * h[i]),
+                ])
+            })
+            .collect();
+        Self::new(xs.to_vec(), pieces)
+    }
+    /// Builds the interpolating spline of `degree` through the points
+    /// `(xs, ys)`, which has continuous derivatives up to
+    /// `degree - 1`. Odd degrees use the not-a-knot condition, joining
+    /// pieces at every x but the `(degree - 1) / 2` nearest each end,
+    /// so a degree of 3 gives the same spline as
+    /// [cubic](#method.cubic) with [NotAKnot](enum.Boundary.html#variant.NotAKnot).
+    /// Even degrees join pieces halfway between the xs instead
+    ///
+    /// # Panics
+    /// If there are fewer than two points, or no more than `degree`,
+    /// `xs` and `ys` have different lengths, or `xs` isn't strictly
+    /// increasing
+    /// ```
+    /// use cakcukus::{traits::TermTrait, Spline};
+    ///
+    /// let xs = [0f64, 1., 2., 3., 4., 5., 6.];
+    /// let ys: Vec<f64> = xs.iter().map(|x| x.powi(5)).collect();
+    /// let spline = Spline::interpolating(&xs, &ys, 5);
+    ///
+    /// // Quintic splines reproduce quintics exactly
+    /// assert!((spline.sum_with_respect_to(&2.5) - 2.5f64.powi(5)).abs() < 1e-9);
+    /// ```
+    pub fn interpolating(xs: &[T], ys: &[T], degree: usize) -> Self {
+        assert!(xs.len() == ys.len(), "Every x needs a y");
+        assert!(
+            xs.len() >= 2 && xs.len() > degree,
+            "A spline needs at least two points, and more than its degree"
+        );
+        assert!(
+            xs.windows(2).all(|pair| pair[0] < pair[1]),
+            "Spline knots must be strictly increasing"
+        );
+        let n = xs.len();
+        let two = T::one() + T::one();
+
+        // The B-spline knots, with the ends repeated degree + 1 times,
+        // leaving n B-splines, one for each point
+        let interior: Vec<T> = if degree % 2 == 1 {
+            let skipped = (degree - 1) / 2;
+            xs[skipped + 1..n - skipped - 1].to_vec()
+        } else {
+            let skipped = degree / 2;
+            xs.windows(2)
+                .map(|pair| (pair[0] + pair[1]) / two)
+                .skip(skipped)
+                .take(n - degree - 1)
+                .collect()
+        };
+        let mut knots = vec![xs[0]; degree + 1];
+        knots.extend(interior);
+        knots.extend(vec![xs[n - 1]; degree + 1]);
+
+        // Each point lies in the last span starting at or before it
+        let span_at = |x: T| {
+            let after = knots.partition_point(|knot| *knot <= x);
+            after.saturating_sub(1).min(n - 1)
+        };
+        let mut matrix = vec![vec![T::zero(); n]; n];
+        for (row, x) in matrix.iter_mut().zip(xs.iter()) {
+            let span = span_at(*x);
+            let u = *x - knots[span];
+            for (r, basis) in basis_on_span(&knots, span, degree).iter().enumerate() {
+                row[span - degree + r] = basis
+                    .iter()
+                    .rev()
+                    .fold(T::zero(), |total, c| total * u + *c);
+            }
+        }
+        let weights = linalg::solve(matrix, ys.to_vec())
+            .expect("Distinct xs always give a unique interpolating spline");
+
+        let pieces = (degree..n)
+            .map(|span| {
+                let mut coefficients = vec![T::zero(); degree + 1];
+                for (r, basis) in basis_on_span(&knots, span, degree).iter().enumerate() {
+                    for (total, c) in coefficients.iter_mut().zip(basis.iter()) {
+                        *total = *total + weights[span - degree + r] * *c;
+                    }
+                }
+                Polynomial::from_coefficients(&coefficients)
+            })
+            .collect();
+        Self::new(knots[degree..=n].to_vec(), pieces)
+    }
+    pub fn knots(&self) -> &[T] {
+        &self.knots
+    }
+    /// The pieces between each pair of knots, each in powers of
+    /// `(x - knot)`
+    pub fn pieces(&self) -> &[Polynomial<T>] {
+        &self.pieces
+    }
+    /// Returns the index of the piece used at `x`, by binary search
+    fn piece_at(&self, x: T) -> usize {
+        let after = self.knots.partition_point(|knot| *knot <= x);
+        after.saturating_sub(1).min(self.pieces.len() - 1)
+    }
+}
+
+impl<T> TermTrait<T> for Spline<T>
+where
+    T: Float + Pow<T, Output = T>,
+{
+    fn sum_with_respect_to(&self, x: &T) -> T {
+        let i = self.piece_at(*x);
+        self.pieces[i].sum_with_respect_to(&(*x - self.knots[i]))
+    }
+}
+
+impl<T> Differentiation<T> for Spline<T>
+where
+    T: Float + Pow<T, Output = T>,
+{
+    fn differentiate(&self, x: &T) -> T {
+        let i = self.piece_at(*x);
+        self.pieces[i].differentiate(&(*x - self.knots[i]))
+    }
+    fn differentiate_self(&self) -> Self {
+        Self::new(
+            self.knots.clone(),
+            self.pieces.iter().map(|p| p.differentiate_self()).collect(),
+        )
+    }
+}
+
+impl<T> Integration<T> for Spline<T>
+where
+    T: Float + Pow<T, Output = T>,
+{
+    /// Intergrates a copy of self, choosing each piece's constant so
+    /// that the result is continuous, and 0 at the first knot
+    fn integrate_self(&self) -> Self {
+        let mut total = T::zero();
+        let mut pieces = Vec::with_capacity(self.pieces.len());
+        for (i, piece) in self.pieces.iter().enumerate() {
+            let mut integrated = piece.integrate_self();
+            integrated.0.push(Term::new(total, T::zero()));
+            integrated.simplify();
+            total = total + piece.integrate(T::zero(), self.knots[i + 1] - self.knots[i]);
+            pieces.push(integrated);
+        }
+        Self::new(self.knots.clone(), pieces)
+    }
+    /// Intergrates piece by piece, splitting the range at each knot
+    fn integrate(&self, lower: T, upper: T) -> T {
+        if upper < lower {
+            return -self.integrate(upper, lower);
+        }
+        let (first, last) = (self.piece_at(lower), self.piece_at(upper));
+        let mut total = T::zero();
+        for i in first..=last {
+            let start = if i == first { lower } else { self.knots[i] };
+            let end = if i == last { upper } else { self.knots[i + 1] };
+            total = total + self.pieces[i].integrate(start - self.knots[i], end - self.knots[i]);
+        }
+        total
+    }
+}
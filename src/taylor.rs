@@ -0,0 +1,155 @@
+//! Taylor and Maclaurin expansions of any
+//! [Differentiation](../traits/trait.Differentiation.html) implementor,
+//! as a [Polynomial](../struct.Polynomial.html).
+
+use num_traits::{Float, Pow};
+
+use crate::{
+    traits::{Differentiation, TermTrait},
+    util::cast,
+    Polynomial, Term,
+};
+
+/// The number of points the (order + 1)th derivative is sampled at
+/// when estimating a remainder bound
+const SAMPLES: usize = 64;
+
+/// Returns the Lagrange bound on the error at `x` of the Taylor
+/// polynomial of `order` around `center`, being
+/// `M |x - center|^(order + 1) / (order + 1)!`, where `max_derivative`
+/// is M, the largest absolute value of the (order + 1)th derivative
+/// between `center` and `x`
+pub fn lagrange_bound<T: Float>(center: T, order: usize, x: T, max_derivative: T) -> T {
+    let mut bound = max_derivative.abs();
+    for k in 1..=order + 1 {
+        bound = bound * (x - center).abs() / cast(k);
+    }
+    bound
+}
+
+/// A Taylor polynomial, along with where it was expanded
+#[derive(Debug, Clone, PartialEq)]
+pub struct Taylor<T>
+where
+    T: Float + Pow<T, Output = T>,
+{
+    /// The expansion, with its terms in powers of x
+    pub polynomial: Polynomial<T>,
+
+    /// The point the expansion was taken around
+    pub center: T,
+
+    /// The largest power of `(x - center)` in the expansion
+    pub order: usize,
+}
+
+impl<T> Taylor<T>
+where
+    T: Float + Pow<T, Output = T>,
+{
+    /// Builds the expansion from its coefficients in powers of
+    /// `(x - center)`, expanding each power with the binomial
+    /// theorem
+    pub fn from_coefficients(coefficients: &[T], center: T) -> Self {
+        let order = coefficients.len().saturating_sub(1);
+        let mut expanded = vec![T::zero(); coefficients.len()];
+        for (k, coefficient) in coefficients.iter().enumerate() {
+            // (x - c)^k = sum of C(k, j) x^j (-c)^(k - j)
+            let mut binomial = T::one();
+            for j in (0..=k).rev() {
+                expanded[j] =
+                    expanded[j] + *coefficient * binomial * (-center).powi((k - j) as i32);
+                binomial = binomial * cast(j) / cast(k - j + 1);
+            }
+        }
+        let mut terms = Vec::with_capacity(expanded.len());
+        for (power, coefficient) in expanded.into_iter().enumerate().rev() {
+            if !coefficient.is_zero() {
+                terms.push(Term::new(coefficient, cast(power)));
+            }
+        }
+        Self {
+            polynomial: Polynomial(terms),
+            center,
+            order,
+        }
+    }
+    /// Returns the Lagrange bound on the error of the expansion at
+    /// `x`, given M, the largest absolute value of the (order + 1)th
+    /// derivative between `center` and `x`, see
+    /// [lagrange_bound](fn.lagrange_bound.html)
+    pub fn lagrange_bound(&self, x: T, max_derivative: T) -> T {
+        lagrange_bound(self.center, self.order, x, max_derivative)
+    }
+}
+
+impl<T> TermTrait<T> for Taylor<T>
+where
+    T: Float + Pow<T, Output = T>,
+{
+    fn sum_with_respect_to(&self, x: &T) -> T {
+        self.polynomial.sum_with_respect_to(x)
+    }
+}
+
+/// Describes a type's ability to be approximated by its Taylor
+/// polynomial, automatically implemented for any type that implements
+/// [Differentiation](../traits/trait.Differentiation.html) and
+/// [TermTrait](../traits/trait.TermTrait.html)
+pub trait TaylorSeries<T>
+where
+    T: Float + Pow<T, Output = T>,
+{
+    /// Returns the Taylor polynomial of self around `center`, up to
+    /// and including the `order`th power
+    /// ```
+    /// use cakcukus::{finite_difference::NumericalFunction, taylor::TaylorSeries, traits::TermTrait};
+    ///
+    /// let exp = NumericalFunction::new(|x: f64| x.exp());
+    ///
+    /// // 1 + x + x^2 / 2 + x^3 / 6
+    /// let taylor = exp.maclaurin(3);
+    ///
+    /// assert!((taylor.sum_with_respect_to(&0.1) - 0.1f64.exp()).abs() < 1e-5);
+    /// ```
+    fn taylor(&self, center: T, order: usize) -> Taylor<T>;
+    /// Returns the Taylor polynomial of self around 0
+    fn maclaurin(&self, order: usize) -> Taylor<T> {
+        self.taylor(T::zero(), order)
+    }
+    /// Estimates the error of the Taylor polynomial at `x` with the
+    /// Lagrange bound, taking M from samples of the (order + 1)th
+    /// derivative between `center` and `x`. A larger value of the
+    /// derivative between the samples is missed, so this is only an
+    /// estimate of the bound, and may fall below the true error
+    fn taylor_remainder_bound(&self, center: T, order: usize, x: T) -> T;
+}
+
+impl<I, T> TaylorSeries<T> for I
+where
+    I: Differentiation<T> + TermTrait<T> + Clone,
+    T: Float + Pow<T, Output = T>,
+{
+    fn taylor(&self, center: T, order: usize) -> Taylor<T> {
+        let mut coefficients = Vec::with_capacity(order + 1);
+        let mut derivative = self.clone();
+        let mut factorial = T::one();
+        for k in 0..=order {
+            if k != 0 {
+                derivative = derivative.differentiate_self();
+                factorial = factorial * cast(k);
+            }
+            coefficients.push(derivative.sum_with_respect_to(&center) / factorial);
+        }
+        Taylor::from_coefficients(&coefficients, center)
+    }
+    fn taylor_remainder_bound(&self, center: T, order: usize, x: T) -> T {
+        let next = self.nth_derivative(order + 1);
+        let mut max_derivative = T::zero();
+        for i in 0..=SAMPLES {
+            let point = center + (x - center) * cast(i) / cast(SAMPLES);
+            max_derivative = max_derivative.max(next.sum_with_respect_to(&point).abs());
+        }
+        lagrange_bound(center, order, x, max_derivative)
+    }
+}
@@ -3,8 +3,10 @@ use num_traits::{Num, Pow};
 #[cfg(feature = "built_in_types")]
 use num_traits::identities::{one, zero};
 
+use crate::traits::TermTrait;
+
 #[cfg(feature = "built_in_types")]
-use crate::{traits::TermTrait, Polynomial, Term};
+use crate::{Polynomial, Term};
 
 /// Describes a type's ability to do basic differentiation
 pub trait Differentiation<T: Num + Pow<T, Output = T> + Copy> {
@@ -12,6 +14,33 @@ pub trait Differentiation<T: Num + Pow<T, Output = T> + Copy> {
     fn differentiate(&self, x: &T) -> T;
     /// Differentiates a copy of self, and returns the copy
     fn differentiate_self(&self) -> Self;
+    /// Differentiates a copy of self `n` times, and returns the copy.
+    /// Returns an unchanged copy when `n` is 0
+    /// ```
+    /// use cakcukus::{terms, traits::Differentiation, Term};
+    ///
+    /// let term: Term<f64> = terms!(2., 5.); // 2x^5
+    ///
+    /// // 2 * 5 * 4 * 3 = 120
+    /// assert_eq!(term.nth_derivative(3), Term::new(120., 2.));
+    /// ```
+    fn nth_derivative(&self, n: usize) -> Self
+    where
+        Self: Clone,
+    {
+        let mut derivative = self.clone();
+        for _ in 0..n {
+            derivative = derivative.differentiate_self();
+        }
+        derivative
+    }
+    /// Differentiates self `n` times, with respect to a given x
+    fn differentiate_nth(&self, n: usize, x: &T) -> T
+    where
+        Self: Clone + TermTrait<T>,
+    {
+        self.nth_derivative(n).sum_with_respect_to(x)
+    }
 }
 
 #[cfg(feature = "built_in_types")]
@@ -22,7 +51,7 @@ where
     fn differentiate(&self, x: &T) -> T {
         let mut total = zero();
         for term in self.0.iter() {
-            total = total + term.differentiate(&x);
+            total = total + term.differentiate(x);
         }
         total
     }
@@ -33,6 +62,20 @@ where
         }
         Self(all)
     }
+    fn nth_derivative(&self, n: usize) -> Self {
+        let mut all = Vec::with_capacity(self.0.len());
+        for term in self.0.iter() {
+            all.push(term.nth_derivative(n))
+        }
+        Self(all)
+    }
+    fn differentiate_nth(&self, n: usize, x: &T) -> T {
+        let mut total = zero();
+        for term in self.0.iter() {
+            total = total + term.differentiate_nth(n, x);
+        }
+        total
+    }
 }
 
 #[cfg(feature = "built_in_types")]
@@ -46,4 +89,21 @@ where
     fn differentiate_self(&self) -> Self {
         Self::new(self.coefficient * self.exponent, self.exponent - one())
     }
+    fn nth_derivative(&self, n: usize) -> Self {
+        // The coefficient is multiplied by the falling factorial
+        // exponent * (exponent - 1) * ... * (exponent - n + 1)
+        let mut coefficient = self.coefficient;
+        let mut exponent = self.exponent;
+        for _ in 0..n {
+            if exponent.is_zero() {
+                return Self::new(zero(), zero());
+            }
+            coefficient = coefficient * exponent;
+            exponent = exponent - one();
+        }
+        Self::new(coefficient, exponent)
+    }
+    fn differentiate_nth(&self, n: usize, x: &T) -> T {
+        self.nth_derivative(n).sum_with_respect_to(x)
+    }
 }
@@ -0,0 +1,127 @@
+use core::fmt::Display;
+
+use num_traits::Float;
+#[cfg(feature = "built_in_types")]
+use num_traits::Pow;
+
+use crate::quadrature::QuadratureError;
+#[cfg(feature = "built_in_types")]
+use crate::{Polynomial, Term};
+
+/// The reasons an improper integral couldn't be found
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImproperIntegrationError {
+    /// The integral grows without bound
+    Divergent,
+    /// The integrand isn't real over part of the range, as for
+    /// x^0.5 when x < 0
+    OutsideDomain,
+}
+
+impl Display for ImproperIntegrationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ImproperIntegrationError::Divergent => write!(f, "the integral does not converge"),
+            ImproperIntegrationError::OutsideDomain => {
+                write!(f, "the integrand is not real over the whole range")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ImproperIntegrationError {}
+
+/// Lets symbolic and numerical improper integrals share an error type,
+/// such as when falling back to [improper](../quadrature/fn.improper.html)
+/// for types that can't be intergrated symbolically
+impl<T> From<ImproperIntegrationError> for QuadratureError<T> {
+    fn from(error: ImproperIntegrationError) -> Self {
+        match error {
+            ImproperIntegrationError::Divergent => QuadratureError::Divergent,
+            ImproperIntegrationError::OutsideDomain => QuadratureError::OutsideDomain,
+        }
+    }
+}
+
+/// Describes a type's ability to be intergrated over infinite
+/// bounds, or over a singularity, by taking limits of its
+/// antiderivative
+pub trait ImproperIntegration<T: Float> {
+    /// Intergrates self between `lower` and `upper`, either of
+    /// which may be infinite. Fractional powers of negative numbers
+    /// aren't real, so ranges reaching below 0 are only allowed for
+    /// whole exponents
+    /// ```
+    /// use cakcukus::{traits::ImproperIntegration, Term};
+    ///
+    /// // The integral of x^-2 from 1 to infinity is 1
+    /// assert_eq!(Term::new(1., -2.).integrate_improper(1., f64::INFINITY), Ok(1.));
+    ///
+    /// // The integral of x^-0.5 from 0 to 1 is 2
+    /// assert_eq!(Term::new(1., -0.5).integrate_improper(0., 1.), Ok(2.));
+    /// ```
+    fn integrate_improper(&self, lower: T, upper: T) -> Result<T, ImproperIntegrationError>;
+}
+
+#[cfg(feature = "built_in_types")]
+impl<T> ImproperIntegration<T> for Term<T>
+where
+    T: Float + Pow<T, Output = T>,
+{
+    fn integrate_improper(&self, lower: T, upper: T) -> Result<T, ImproperIntegrationError> {
+        if self.coefficient.is_zero() || lower == upper {
+            return Ok(T::zero());
+        }
+        if lower > upper {
+            return self.integrate_improper(upper, lower).map(|i| -i);
+        }
+        let exponent = self.exponent;
+        let raised = exponent + T::one();
+
+        // Negative numbers only have real powers when they're whole
+        if lower < T::zero() && exponent.fract() != T::zero() {
+            return Err(ImproperIntegrationError::OutsideDomain);
+        }
+
+        // x^e is only integrable at 0 when e > -1, and at
+        // infinity when e < -1
+        let through_zero = lower <= T::zero() && upper >= T::zero();
+        if exponent < T::zero() && through_zero && raised <= T::zero() {
+            return Err(ImproperIntegrationError::Divergent);
+        }
+        if (lower.is_infinite() || upper.is_infinite()) && raised >= T::zero() {
+            return Err(ImproperIntegrationError::Divergent);
+        }
+
+        let antiderivative = |x: T| {
+            if x.is_infinite() || x.is_zero() {
+                // Both limits are 0 whenever the integral converges
+                T::zero()
+            } else if raised.is_zero() {
+                self.coefficient * x.abs().ln()
+            } else {
+                self.coefficient * x.pow(raised) / raised
+            }
+        };
+        Ok(antiderivative(upper) - antiderivative(lower))
+    }
+}
+
+#[cfg(feature = "built_in_types")]
+impl<T> ImproperIntegration<T> for Polynomial<T>
+where
+    T: Float + Pow<T, Output = T>,
+{
+    /// Intergrates each term in turn. Like terms are combined
+    /// first, as terms with different exponents diverge at different
+    /// rates, so their divergences can't cancel
+    fn integrate_improper(&self, lower: T, upper: T) -> Result<T, ImproperIntegrationError> {
+        let mut simplified = self.clone();
+        simplified.simplify();
+        let mut total = T::zero();
+        for term in simplified.0.iter() {
+            total = total + term.integrate_improper(lower, upper)?;
+        }
+        Ok(total)
+    }
+}
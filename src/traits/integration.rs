@@ -9,8 +9,28 @@ use crate::{traits::TermTrait, Polynomial, Term};
 pub trait Integration<T: Num + Pow<T, Output = T> + Copy> {
     /// Intergrates a copy of self, and returns the copy
     fn integrate_self(&self) -> Self;
-    /// Returns self, intergrated to the upper and lower bound
+    /// Returns self, intergrated from the lower to the upper bound
     fn integrate(&self, lower: T, upper: T) -> T;
+    /// Intergrates a copy of self `n` times, and returns the copy.
+    /// Every constant of integration is taken to be 0
+    /// ```
+    /// use cakcukus::{terms, traits::Integration, Term};
+    ///
+    /// let term: Term<f64> = terms!(120., 2.); // 120x^2
+    ///
+    /// // 120 / (3 * 4 * 5) = 2
+    /// assert_eq!(term.nth_antiderivative(3), Term::new(2., 5.));
+    /// ```
+    fn nth_antiderivative(&self, n: usize) -> Self
+    where
+        Self: Clone,
+    {
+        let mut antiderivative = self.clone();
+        for _ in 0..n {
+            antiderivative = antiderivative.integrate_self();
+        }
+        antiderivative
+    }
 }
 
 #[cfg(feature = "built_in_types")]
@@ -29,10 +49,18 @@ where
     fn integrate(&self, lower: T, upper: T) -> T {
         let mut total = zero();
         for x in self.0.iter() {
-            total = total + x.sum_with_respect_to(&upper) - x.sum_with_respect_to(&lower);
+            total = total + x.integrate(lower, upper);
         }
         total
     }
+
+    fn nth_antiderivative(&self, n: usize) -> Self {
+        let mut all = Vec::with_capacity(self.0.len());
+        for x in self.0.iter() {
+            all.push(x.nth_antiderivative(n))
+        }
+        Polynomial(all)
+    }
 }
 
 #[cfg(feature = "built_in_types")]
@@ -45,8 +73,20 @@ where
         Self::new(self.coefficient / exponent, exponent)
     }
 
-    fn integrate(&self, upper: T, lower: T) -> T {
+    fn integrate(&self, lower: T, upper: T) -> T {
         let intergrated = self.integrate_self();
         intergrated.sum_with_respect_to(&upper) - intergrated.sum_with_respect_to(&lower)
     }
+
+    fn nth_antiderivative(&self, n: usize) -> Self {
+        // The coefficient is divided by the rising factorial
+        // (exponent + 1) * (exponent + 2) * ... * (exponent + n)
+        let mut divisor = one();
+        let mut exponent = self.exponent;
+        for _ in 0..n {
+            exponent = exponent + one();
+            divisor = divisor * exponent;
+        }
+        Self::new(self.coefficient / divisor, exponent)
+    }
 }
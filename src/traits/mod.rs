@@ -1,10 +1,12 @@
 use num_traits::{Num, Pow};
 
 mod differentiation;
+mod improper_integration;
 mod integration;
 mod term;
 
 pub use differentiation::Differentiation;
+pub use improper_integration::{ImproperIntegration, ImproperIntegrationError};
 pub use integration::Integration;
 pub use term::TermTrait;
 
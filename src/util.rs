@@ -0,0 +1,28 @@
+//! Numeric helpers shared across the crate.
+
+#[cfg(feature = "built_in_types")]
+use num_traits::{identities::zero, Num};
+use num_traits::{NumCast, ToPrimitive};
+
+/// Casts a `u32` into any numeric type, using only
+/// additions so that no extra bounds are needed on `T`
+#[cfg(feature = "built_in_types")]
+pub(crate) fn from_u32<T: Num + Clone>(n: u32) -> T {
+    let mut result = zero::<T>();
+    for bit in (0..32).rev() {
+        result = result.clone() + result;
+        if n & (1 << bit) != 0 {
+            result = result + T::one();
+        }
+    }
+    result
+}
+
+/// Casts a primitive number, such as an f64 constant or a count,
+/// into `T`
+///
+/// # Panics
+/// If `value` can't be represented in `T`
+pub(crate) fn cast<T: NumCast, N: ToPrimitive>(value: N) -> T {
+    T::from(value).expect("The value should be representable in `T`")
+}
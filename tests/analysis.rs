@@ -0,0 +1,96 @@
+use cakcukus::{
+    analysis::{CriticalPoint, CurveAnalysis},
+    finite_difference::NumericalFunction,
+};
+
+#[cfg(feature = "built_in_types")]
+use cakcukus::{
+    analysis::{Concavity, Monotonicity},
+    terms, Polynomial,
+};
+
+#[cfg(feature = "built_in_types")]
+#[test]
+fn cubic_with_two_turning_points() {
+    // x^3 - 3x, with f' = 3(x - 1)(x + 1) and f'' = 6x
+    let p: Polynomial<f64> = terms!(1., 3., -3., 1.);
+
+    let critical = p.critical_points(-2., 3.);
+    assert_eq!(critical.len(), 2);
+    assert!((critical[0] + 1.).abs() < 1e-12 && (critical[1] - 1.).abs() < 1e-12);
+
+    let inflection = p.inflection_points(-2., 3.);
+    assert_eq!(inflection.len(), 1);
+    assert!(inflection[0].abs() < 1e-12);
+
+    let kinds: Vec<Monotonicity> = p
+        .monotonicity(-2., 3.)
+        .into_iter()
+        .map(|(_, _, kind)| kind)
+        .collect();
+    assert_eq!(
+        kinds,
+        vec![
+            Monotonicity::Increasing,
+            Monotonicity::Decreasing,
+            Monotonicity::Increasing
+        ]
+    );
+
+    let concavity = p.concavity(-2., 3.);
+    assert_eq!(concavity.len(), 2);
+    assert_eq!((concavity[0].0, concavity[0].2), (-2., Concavity::Down));
+    assert!(concavity[1].0.abs() < 1e-12);
+    assert_eq!((concavity[1].1, concavity[1].2), (3., Concavity::Up));
+}
+
+#[cfg(feature = "built_in_types")]
+#[test]
+fn flat_points() {
+    // x^3 flattens at 0 without turning, and x^4 turns there without
+    // an inflection, even though both second derivatives are 0
+    let cube: Polynomial<f64> = Polynomial(vec![terms!(1., 3.)]);
+    let quartic: Polynomial<f64> = Polynomial(vec![terms!(1., 4.)]);
+
+    assert_eq!(
+        cube.classify_extrema(-1., 1.),
+        vec![(0., CriticalPoint::Saddle)]
+    );
+    assert_eq!(
+        cube.monotonicity(-1., 1.),
+        vec![(-1., 1., Monotonicity::Increasing)]
+    );
+    assert_eq!(cube.inflection_points(-1., 1.), vec![0.]);
+
+    assert_eq!(
+        quartic.classify_extrema(-1., 1.),
+        vec![(0., CriticalPoint::Minimum)]
+    );
+    assert!(quartic.inflection_points(-1., 1.).is_empty());
+    assert_eq!(quartic.concavity(-1., 1.), vec![(-1., 1., Concavity::Up)]);
+
+    // A line has no critical points, and is straight everywhere
+    let line: Polynomial<f64> = terms!(2., 1., 1., 0.);
+    assert!(line.critical_points(-1., 1.).is_empty());
+    assert_eq!(
+        line.concavity(-1., 1.),
+        vec![(-1., 1., Concavity::Straight)]
+    );
+}
+
+#[test]
+fn numerical_functions() {
+    let pi = core::f64::consts::PI;
+    let sine = NumericalFunction::new(|x: f64| x.sin());
+
+    let extrema = sine.classify_extrema(0.5, 2. * pi);
+    assert_eq!(extrema.len(), 2);
+    assert!((extrema[0].0 - pi / 2.).abs() < 1e-6);
+    assert_eq!(extrema[0].1, CriticalPoint::Maximum);
+    assert!((extrema[1].0 - 3. * pi / 2.).abs() < 1e-6);
+    assert_eq!(extrema[1].1, CriticalPoint::Minimum);
+
+    let inflection = sine.inflection_points(0.5, 2. * pi);
+    assert_eq!(inflection.len(), 1);
+    assert!((inflection[0] - pi).abs() < 1e-5);
+}
@@ -0,0 +1,71 @@
+#[cfg(feature = "built_in_types")]
+use cakcukus::{
+    bernstein::{Bernstein, Bezier},
+    exact::Rational,
+    terms,
+    traits::{Differentiation, Integration, TermTrait},
+    Polynomial,
+};
+
+#[cfg(feature = "built_in_types")]
+#[test]
+fn basis_conversion() {
+    // Exactly, with rational coefficients
+    let r = Rational::from_integer;
+    let p = terms!(r(4), r(3), r(-1), r(2), r(2), r(0)); // 4t^3 - t^2 + 2
+    let bernstein = Bernstein::from_polynomial(&p);
+
+    assert_eq!(
+        bernstein.coefficients(),
+        &[r(2), r(2), Rational::new(5, 3), r(5)]
+    );
+    assert_eq!(bernstein.to_polynomial(), p);
+    for t in [r(0), Rational::new(1, 3), r(1)].iter() {
+        assert_eq!(bernstein.sum_with_respect_to(t), p.sum_with_respect_to(t));
+    }
+    assert_eq!(bernstein.elevate().to_polynomial(), p);
+}
+
+#[cfg(feature = "built_in_types")]
+#[test]
+fn subdivision_and_calculus() {
+    let p: Polynomial<f64> = terms!(1., 4., -3., 2., 0.5, 1., 2., 0.);
+    let bernstein = Bernstein::from_polynomial(&p);
+
+    let (left, right) = bernstein.subdivide(0.25);
+    for s in [0., 0.3, 0.8, 1.].iter() {
+        let expected = p.sum_with_respect_to(&(0.25 * s));
+        assert!((left.sum_with_respect_to(s) - expected).abs() < 1e-12);
+        let expected = p.sum_with_respect_to(&(0.25 + 0.75 * s));
+        assert!((right.sum_with_respect_to(s) - expected).abs() < 1e-12);
+    }
+
+    assert!((bernstein.differentiate(&0.6) - p.differentiate(&0.6)).abs() < 1e-12);
+    assert!((bernstein.integrate(0.2, 0.9) - p.integrate(0.2, 0.9)).abs() < 1e-12);
+}
+
+#[cfg(feature = "built_in_types")]
+#[test]
+fn curves() {
+    // A straight line in 3D, with its control points bunched up
+    let line = Bezier::new(vec![[0f64, 0., 0.], [1., 2., 2.], [2., 4., 4.]]);
+
+    assert_eq!(line.point(0.5), [1., 2., 2.]);
+    assert!((line.arc_length() - 6.).abs() < 1e-12);
+    assert!((line.arc_length_between(0., 0.5) - 3.).abs() < 1e-12);
+    assert!((line.speed(0.3) - 6.).abs() < 1e-12);
+
+    let elevated = line.elevate();
+    assert_eq!(elevated.degree(), 3);
+    let (first, second) = elevated.subdivide(0.5);
+    assert_eq!(first.control_points()[3], second.control_points()[0]);
+    assert!((first.arc_length() - 3.).abs() < 1e-12);
+
+    // The hodograph of a quadratic is a line through n(P1 - P0) and
+    // n(P2 - P1)
+    let quadratic = Bezier::new(vec![[0., 0.], [1., 2.], [3., 2.]]);
+    assert_eq!(
+        quadratic.derivative().control_points(),
+        &[[2., 4.], [4., 0.]]
+    );
+}
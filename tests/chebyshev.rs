@@ -0,0 +1,52 @@
+#[cfg(feature = "built_in_types")]
+use cakcukus::{
+    chebyshev::{chebyshev_approximation, Accuracy, Chebyshev},
+    traits::{Differentiation, Integration, TermTrait},
+};
+
+#[cfg(feature = "built_in_types")]
+#[test]
+fn approximates_to_tolerance() {
+    let f = |x: f64| (3. * x).sin() + x.cos();
+    let approximation = chebyshev_approximation(f, [-2., 3.], Accuracy::Tolerance(1e-12));
+
+    assert!(approximation.degree() < 64);
+    for i in 0..=50 {
+        let x = -2. + 0.1 * i as f64;
+        assert!((approximation.sum_with_respect_to(&x) - f(x)).abs() < 1e-11);
+    }
+
+    // A fixed degree of an exact polynomial reproduces it
+    let cubic = |x: f64| x * x * x - x;
+    let exact = chebyshev_approximation(cubic, [0., 1.], Accuracy::Degree(3));
+    assert_eq!(exact.degree(), 3);
+    assert!((exact.sum_with_respect_to(&0.3) - cubic(0.3)).abs() < 1e-14);
+}
+
+#[cfg(feature = "built_in_types")]
+#[test]
+fn calculus_in_the_chebyshev_basis() {
+    let approximation = Chebyshev::new(|x: f64| x.exp(), 0., 2., 30);
+    assert!(approximation.error_estimate() < 1e-14);
+
+    let derivative = approximation.differentiate_self();
+    assert!((derivative.sum_with_respect_to(&1.3) - 1.3f64.exp()).abs() < 1e-12);
+    assert!((approximation.differentiate(&0.5) - 0.5f64.exp()).abs() < 1e-12);
+
+    let integral = approximation.integrate_self();
+    assert!(integral.sum_with_respect_to(&0.).abs() < 1e-14);
+    assert!((integral.sum_with_respect_to(&2.) - (2f64.exp() - 1.)).abs() < 1e-12);
+    assert!((approximation.integrate(0.5, 1.5) - (1.5f64.exp() - 0.5f64.exp())).abs() < 1e-12);
+}
+
+#[cfg(feature = "built_in_types")]
+#[test]
+fn converts_to_monomials() {
+    let approximation = Chebyshev::new(|x: f64| 2. * x * x - 3. * x + 1., 1., 4., 2);
+    let polynomial = approximation.to_polynomial();
+
+    assert_eq!(polynomial.0.len(), 3);
+    for (term, expected) in polynomial.0.iter().zip([2., -3., 1.].iter()) {
+        assert!((term.coefficient - expected).abs() < 1e-12);
+    }
+}
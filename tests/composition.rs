@@ -0,0 +1,59 @@
+#[cfg(feature = "built_in_types")]
+use cakcukus::{exact::Rational, terms, traits::TermTrait, Polynomial};
+
+#[cfg(feature = "built_in_types")]
+#[test]
+fn compose_agrees_with_evaluation() {
+    // 2x^5 - x^2 + 3, and x^2 + x - 1
+    let outer: Polynomial<f64> = Polynomial(vec![terms!(2., 5.), terms!(-1., 2.), terms!(3., 0.)]);
+    let inner: Polynomial<f64> = terms!(1., 2., 1., 1., -1., 0.);
+    let composed = outer.compose(&inner);
+
+    assert_eq!(composed.0[0], terms!(2., 10.));
+    for x in [-1.5f64, -0.3, 0., 0.7, 1.2].iter() {
+        let expected = outer.sum_with_respect_to(&inner.sum_with_respect_to(x));
+        assert!((composed.sum_with_respect_to(x) - expected).abs() < 1e-9);
+    }
+}
+
+#[cfg(feature = "built_in_types")]
+#[test]
+fn sparse_high_degree_shift_is_exact() {
+    let r = Rational::from_integer;
+    // x^40 + x, shifted by 1
+    let p = Polynomial(vec![terms!(r(1), r(40)), terms!(r(1), r(1))]);
+    let shifted = p.shift(r(1));
+
+    assert_eq!(shifted.0.len(), 41);
+    assert_eq!(shifted.0[0], terms!(r(1), r(40)));
+    // The x^20 coefficient is C(40, 20)
+    let middle = shifted
+        .0
+        .iter()
+        .find(|term| term.exponent == r(20))
+        .unwrap();
+    assert_eq!(middle.coefficient, r(137_846_528_820));
+    // Only x contributes to the x and constant terms beyond the binomials
+    assert_eq!(shifted.0[39], terms!(r(41), r(1)));
+    assert_eq!(shifted.0[40], terms!(r(2), r(0)));
+
+    let back = shifted.shift(r(-1));
+    assert_eq!(back, p);
+}
+
+#[cfg(feature = "built_in_types")]
+#[test]
+fn shift_and_scale_match_composition() {
+    let r = Rational::from_integer;
+    let p = Polynomial(vec![
+        terms!(r(3), r(12)),
+        terms!(r(-2), r(5)),
+        terms!(r(7), r(0)),
+    ]);
+
+    let a = Rational::new(-3, 2);
+    assert_eq!(p.shift(a), p.compose(&terms!(r(1), r(1), a, r(0))));
+
+    let k = Rational::new(2, 3);
+    assert_eq!(p.scale(k), p.compose(&Polynomial(vec![terms!(k, r(1))])));
+}
@@ -18,5 +18,36 @@ fn differentiate_return() {
     let differentiated = terms.differentiate_self();
 
     // At x = 7, the gradient should be 25
-    assert!(25. - differentiated.sum_with_respect_to(&7.) < f32::EPSILON);
+    assert!((25. - differentiated.sum_with_respect_to(&7.)).abs() < f32::EPSILON);
+}
+
+#[cfg(feature = "built_in_types")]
+#[test]
+fn nth_derivative() {
+    // Build the initial equation, being x^4 + 2x^3 - x + 7
+    let terms: Polynomial<f64> = cakcukus::terms!(1., 4., 2., 3., -1., 1., 7., 0.);
+
+    // Chaining differentiate_self three times gives 24x + 12
+    let chained = terms
+        .differentiate_self()
+        .differentiate_self()
+        .differentiate_self();
+
+    assert!((terms.nth_derivative(3).sum_with_respect_to(&2.) - 60.).abs() < f64::EPSILON);
+    assert!(
+        (chained.sum_with_respect_to(&2.) - terms.differentiate_nth(3, &2.)).abs() < f64::EPSILON
+    );
+
+    // Differentiating past the degree leaves nothing behind
+    assert!(terms.differentiate_nth(5, &3.).abs() < f64::EPSILON);
+    assert_eq!(terms.nth_derivative(0), terms);
+}
+
+#[cfg(feature = "built_in_types")]
+#[test]
+fn nth_derivative_unsigned() {
+    let term: Term<u32> = Term::new(3, 2);
+
+    assert_eq!(term.nth_derivative(2), Term::new(6, 0));
+    assert_eq!(term.nth_derivative(3), Term::new(0, 0));
 }
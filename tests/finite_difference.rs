@@ -0,0 +1,57 @@
+use cakcukus::{
+    finite_difference::{derivative, difference, richardson, NumericalFunction, Stencil},
+    traits::{Calculus, Differentiation, Integration, TermTrait},
+};
+
+#[test]
+fn stencils() {
+    let f = |x: f64| x.exp();
+    let exact = 2f64.exp();
+
+    assert!((derivative(&f, 2., 1, Stencil::Forward) - exact).abs() < 1e-6);
+    assert!((derivative(&f, 2., 1, Stencil::Backward) - exact).abs() < 1e-6);
+    assert!((derivative(&f, 2., 1, Stencil::Central) - exact).abs() < 1e-9);
+    assert!((derivative(&f, 2., 1, Stencil::FivePoint) - exact).abs() < 1e-11);
+    assert!((derivative(&f, 2., 2, Stencil::Central) - exact).abs() < 1e-5);
+
+    // Central differences are exact for quadratics, whatever the step
+    let quadratic = |x: f64| 3. * x * x - x;
+    assert!((difference(&quadratic, 1., 1, Stencil::Central, 0.5) - 5.).abs() < 1e-12);
+    assert!((difference(&quadratic, 1., 2, Stencil::Central, 0.5) - 6.).abs() < 1e-12);
+}
+
+#[test]
+fn richardson_extrapolation() {
+    let f = |x: f64| x.sin();
+
+    let first = richardson(&f, 0.5, 1);
+    assert!((first.value - 0.5f64.cos()).abs() < 1e-12);
+    assert!(first.error < 1e-10);
+
+    let third = richardson(&f, 0.5, 3);
+    assert!((third.value + 0.5f64.cos()).abs() < 1e-7);
+}
+
+/// Stands in for existing code written against the traits
+fn gradient_and_area<C: Calculus<f64>>(c: &C) -> (f64, f64) {
+    (c.differentiate(&1.), c.integrate(0., 1.))
+}
+
+#[test]
+fn wrapper_implements_calculus() {
+    let exp = NumericalFunction::new(|x: f64| x.exp());
+    let e = 1f64.exp();
+
+    let (gradient, area) = gradient_and_area(&exp);
+    assert!((gradient - e).abs() < 1e-10);
+    assert!((area - (e - 1.)).abs() < 1e-10);
+
+    // Differentiating an antiderivative gives back the function exactly
+    let round_trip = exp.integrate_self().differentiate_self();
+    assert_eq!(round_trip.order(), 0);
+    assert!((round_trip.sum_with_respect_to(&2.) - 2f64.exp()).abs() < f64::EPSILON);
+
+    // The second antiderivative of e^x that is 0 at x = 0 is e^x - 1 - x
+    let twice = exp.nth_antiderivative(2);
+    assert!((twice.sum_with_respect_to(&1.) - (e - 2.)).abs() < 1e-10);
+}
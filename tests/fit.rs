@@ -0,0 +1,73 @@
+#[cfg(feature = "built_in_types")]
+use cakcukus::{
+    fit::FitError,
+    traits::{Differentiation, TermTrait},
+    Polynomial,
+};
+
+#[cfg(feature = "built_in_types")]
+#[test]
+fn recovers_exact_polynomials() {
+    // 0.5x^3 - 2x + 3, sampled without noise
+    let xs: Vec<f64> = (0..12).map(|i| i as f64 * 0.5 - 3.).collect();
+    let ys: Vec<f64> = xs.iter().map(|x| 0.5 * x.powi(3) - 2. * x + 3.).collect();
+
+    let fit = Polynomial::fit(&xs, &ys, 3).unwrap();
+
+    for (x, y) in xs.iter().zip(ys.iter()) {
+        assert!((fit.polynomial.sum_with_respect_to(x) - y).abs() < 1e-10);
+    }
+    assert!(fit.residuals.iter().all(|r| r.abs() < 1e-10));
+    assert!((fit.r_squared - 1.).abs() < 1e-12);
+    assert!(fit.standard_errors.unwrap().iter().all(|e| *e < 1e-10));
+}
+
+#[cfg(feature = "built_in_types")]
+#[test]
+fn straight_line_diagnostics() {
+    let xs = [1f64, 2., 3., 4., 5.];
+    let ys = [2.2, 2.8, 4.1, 4.9, 6.2];
+
+    let fit = Polynomial::fit(&xs, &ys, 1).unwrap();
+
+    // Slope and intercept both 1.01, from the closed form
+    assert!((fit.polynomial.sum_with_respect_to(&0.) - 1.01).abs() < 1e-10);
+    assert!((fit.polynomial.differentiate(&0.) - 1.01).abs() < 1e-10);
+    let sum: f64 = fit.residuals.iter().sum();
+    assert!(sum.abs() < 1e-10);
+    // The slope's standard error is sqrt(s^2 / Sxx), with Sxx = 10
+    let squares: f64 = fit.residuals.iter().map(|r| r * r).sum();
+    let standard_errors = fit.standard_errors.unwrap();
+    assert!((standard_errors[1] - (squares / 3. / 10.).sqrt()).abs() < 1e-10);
+    assert!(fit.r_squared > 0.98 && fit.r_squared < 1.);
+}
+
+#[cfg(feature = "built_in_types")]
+#[test]
+fn weights_and_errors() {
+    // The outlier at x = 3 is ignored when given no weight
+    let xs = [0f64, 1., 2., 3.];
+    let ys = [1., 3., 5., 100.];
+    let fit = Polynomial::fit_weighted(&xs, &ys, &[1., 1., 1., 0.], 1).unwrap();
+    assert!((fit.polynomial.sum_with_respect_to(&3.) - 7.).abs() < 1e-10);
+
+    // A line through two points leaves nothing to estimate the noise from
+    let exact = Polynomial::fit(&[0., 1.], &[1., 4.], 1).unwrap();
+    assert_eq!(exact.standard_errors, None);
+    let weighted = Polynomial::fit_weighted(&xs, &ys, &[1., 1., 0., 0.], 1).unwrap();
+    assert_eq!(weighted.standard_errors, None);
+
+    assert_eq!(
+        Polynomial::fit_weighted(&xs, &ys, &[0.; 4], 0).unwrap_err(),
+        FitError::TooFewPoints
+    );
+
+    assert_eq!(
+        Polynomial::fit(&xs, &ys, 4).unwrap_err(),
+        FitError::TooFewPoints
+    );
+    assert_eq!(
+        Polynomial::fit(&[1., 1., 1.], &[1., 2., 3.], 1).unwrap_err(),
+        FitError::RankDeficient
+    );
+}
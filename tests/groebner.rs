@@ -0,0 +1,121 @@
+#[cfg(feature = "built_in_types")]
+use cakcukus::{
+    exact::{Gf, Rational},
+    groebner::Ideal,
+    MonomialOrder, MultiPolynomial, MultiTerm,
+};
+
+#[cfg(feature = "built_in_types")]
+fn polynomial(terms: &[(i64, &[u32])]) -> MultiPolynomial<Rational> {
+    MultiPolynomial(
+        terms
+            .iter()
+            .map(|(c, e)| MultiTerm::new(Rational::from_integer(*c), e.to_vec()))
+            .collect(),
+    )
+}
+
+#[cfg(feature = "built_in_types")]
+#[test]
+fn triangular_system() {
+    // x^2 + y + z - 1, x + y^2 + z - 1, x + y + z^2 - 1
+    let generators = vec![
+        polynomial(&[(1, &[2]), (1, &[0, 1]), (1, &[0, 0, 1]), (-1, &[])]),
+        polynomial(&[(1, &[1]), (1, &[0, 2]), (1, &[0, 0, 1]), (-1, &[])]),
+        polynomial(&[(1, &[1]), (1, &[0, 1]), (1, &[0, 0, 2]), (-1, &[])]),
+    ];
+
+    let ideal = Ideal::new(&generators, MonomialOrder::Lex);
+
+    // Eliminating x and y leaves z^6 - 4z^4 + 4z^3 - z^2
+    let only_z = ideal.eliminate(2);
+    assert_eq!(only_z.len(), 1);
+    assert_eq!(
+        only_z[0],
+        polynomial(&[
+            (1, &[0, 0, 6]),
+            (-4, &[0, 0, 4]),
+            (4, &[0, 0, 3]),
+            (-1, &[0, 0, 2])
+        ])
+    );
+
+    // Every generator belongs to the ideal, but x doesn't
+    for generator in generators.iter() {
+        assert!(ideal.contains(generator));
+    }
+    assert!(!ideal.contains(&polynomial(&[(1, &[1])])));
+    assert!(!ideal.is_trivial());
+}
+
+#[cfg(feature = "built_in_types")]
+#[test]
+fn membership_across_orders() {
+    // xy - 1, y^2 - x
+    let generators = vec![
+        polynomial(&[(1, &[1, 1]), (-1, &[])]),
+        polynomial(&[(1, &[0, 2]), (-1, &[1])]),
+    ];
+    // x^2y^2 - 1 = (xy + 1)(xy - 1)
+    let member = polynomial(&[(1, &[2, 2]), (-1, &[])]);
+
+    for order in [
+        MonomialOrder::Lex,
+        MonomialOrder::GradedLex,
+        MonomialOrder::GradedReverseLex,
+        MonomialOrder::Elimination(1),
+    ]
+    .iter()
+    {
+        let ideal = Ideal::new(&generators, *order);
+        assert!(ideal.contains(&member));
+        assert!(!ideal.contains(&polynomial(&[(1, &[0, 1])])));
+    }
+
+    // Only the elimination orders are guaranteed to leave a basis
+    // in y alone, which here is y^3 - 1
+    for order in [MonomialOrder::Lex, MonomialOrder::Elimination(1)].iter() {
+        assert_eq!(
+            Ideal::new(&generators, *order).eliminate(1),
+            vec![polynomial(&[(1, &[0, 3]), (-1, &[])])]
+        );
+    }
+}
+
+#[cfg(feature = "built_in_types")]
+#[test]
+fn finite_field() {
+    type F = Gf<7>;
+
+    // x + y, x - y and y - 1 have no common solution
+    let generators: Vec<MultiPolynomial<F>> = vec![
+        MultiPolynomial(vec![
+            MultiTerm::new(F::new(1), vec![1]),
+            MultiTerm::new(F::new(1), vec![0, 1]),
+        ]),
+        MultiPolynomial(vec![
+            MultiTerm::new(F::new(1), vec![1]),
+            MultiTerm::new(F::new(-1), vec![0, 1]),
+        ]),
+        MultiPolynomial(vec![
+            MultiTerm::new(F::new(1), vec![0, 1]),
+            MultiTerm::new(F::new(-1), vec![]),
+        ]),
+    ];
+
+    let ideal = Ideal::new(&generators, MonomialOrder::GradedReverseLex);
+    assert!(ideal.is_trivial());
+
+    assert_eq!(F::new(3) * F::new(5), F::new(1));
+    assert_eq!(F::new(1) / F::new(3), F::new(5));
+}
+
+#[cfg(feature = "built_in_types")]
+#[test]
+#[should_panic(expected = "Rational numerator overflowed")]
+fn rational_overflow() {
+    let half_max = Rational::new(i64::MAX / 2 + 7, 1);
+
+    // Doubling this no longer fits in an i64, so mustn't wrap around
+    let _ = half_max + half_max;
+}
@@ -0,0 +1,109 @@
+use cakcukus::quadrature::{improper, QuadratureError};
+
+#[test]
+fn numerical() {
+    // The integral of x^-2 from 1 to infinity is 1
+    let estimate = improper(|x: f64| x.powi(-2), 1., f64::INFINITY, 1e-10, 100).unwrap();
+    assert!((estimate.value - 1.).abs() < 1e-10);
+
+    // The integral of x^-0.5 from 0 to 1 is 2, despite the singularity at 0
+    let estimate = improper(|x: f64| x.powf(-0.5), 0., 1., 1e-10, 100).unwrap();
+    assert!((estimate.value - 2.).abs() < 1e-9);
+
+    // e^x from -infinity to 0 is 1, and swapping the bounds negates it
+    let estimate = improper(|x: f64| x.exp(), 0., f64::NEG_INFINITY, 1e-10, 100).unwrap();
+    assert!((estimate.value + 1.).abs() < 1e-10);
+
+    // 1 / x diverges at 0
+    assert_eq!(
+        improper(|x: f64| 1. / x, 0., 1., 1e-10, 100),
+        Err(QuadratureError::Divergent)
+    );
+
+    // x^-1.01 converges, to 100, but too slowly to reach the tolerance
+    match improper(|x: f64| x.powf(-1.01), 1., f64::INFINITY, 1e-10, 100) {
+        Err(QuadratureError::NotConverged(_)) => {}
+        other => panic!("expected NotConverged, got {:?}", other),
+    }
+
+    // The square root isn't defined for half of the range
+    assert_eq!(
+        improper(|x: f64| x.sqrt(), -1., 1., 1e-10, 100),
+        Err(QuadratureError::OutsideDomain)
+    );
+}
+
+#[cfg(feature = "built_in_types")]
+#[test]
+fn symbolic() {
+    use cakcukus::{
+        terms,
+        traits::{ImproperIntegration, ImproperIntegrationError, Integration},
+        Polynomial, Term,
+    };
+
+    let inverse_square: Term<f64> = terms!(1., -2.);
+    assert_eq!(inverse_square.integrate_improper(1., f64::INFINITY), Ok(1.));
+    assert_eq!(
+        inverse_square.integrate_improper(f64::NEG_INFINITY, -2.),
+        Ok(0.5)
+    );
+    assert_eq!(
+        inverse_square.integrate_improper(-1., 1.),
+        Err(ImproperIntegrationError::Divergent)
+    );
+
+    let root: Term<f64> = terms!(1., -0.5);
+    assert_eq!(root.integrate_improper(0., 4.), Ok(4.));
+    assert_eq!(
+        root.integrate_improper(1., f64::INFINITY),
+        Err(ImproperIntegrationError::Divergent)
+    );
+
+    // 1 / x has a logarithmic antiderivative
+    let inverse: Term<f64> = terms!(1., -1.);
+    assert!((inverse.integrate_improper(1., 1f64.exp()).unwrap() - 1.).abs() < 1e-15);
+    assert_eq!(
+        inverse.integrate_improper(1., f64::INFINITY),
+        Err(ImproperIntegrationError::Divergent)
+    );
+
+    // Finite integrals agree with Integration::integrate
+    let polynomial: Polynomial<f64> = terms!(3., 2., -2., 1., 1., 0.);
+    let integral = polynomial.integrate(-1., 2.);
+    let improper = polynomial.integrate_improper(-1., 2.).unwrap();
+    assert!((improper - integral).abs() < 1e-12);
+    assert!((integral - 9.).abs() < 1e-12);
+}
+
+#[cfg(feature = "built_in_types")]
+#[test]
+fn outside_domain() {
+    use cakcukus::{
+        terms,
+        traits::{ImproperIntegration, ImproperIntegrationError},
+        Term,
+    };
+
+    let inverse_root: Term<f64> = terms!(1., -0.5);
+    assert_eq!(
+        inverse_root.integrate_improper(-1., 1.),
+        Err(ImproperIntegrationError::OutsideDomain)
+    );
+
+    let root: Term<f64> = terms!(1., 0.5);
+    assert_eq!(
+        root.integrate_improper(-1., 0.),
+        Err(ImproperIntegrationError::OutsideDomain)
+    );
+
+    // Whole exponents are still fine below 0
+    let cube: Term<f64> = terms!(4., 3.);
+    assert_eq!(cube.integrate_improper(-1., 0.), Ok(-1.));
+
+    // The same reasons are given by numerical integration
+    let error: QuadratureError<f64> = ImproperIntegrationError::OutsideDomain.into();
+    assert_eq!(error, QuadratureError::OutsideDomain);
+    let error: QuadratureError<f64> = ImproperIntegrationError::Divergent.into();
+    assert_eq!(error, QuadratureError::Divergent);
+}
@@ -8,7 +8,7 @@ use cakcukus::{
 #[test]
 fn intergrate_return() {
     // Builds the initial equaton, being x^4 + 2x + 4
-    let terms = Polynomial(vec![
+    let terms: Polynomial<f32> = Polynomial(vec![
         Term::new(1., 4.),
         Term::new(2., 1.),
         Term::new(4., 0.),
@@ -17,5 +17,30 @@ fn intergrate_return() {
     // This then intergrates to 0.2x^5 + x^2 + 4x
     let intergrated = terms.integrate_self();
 
-    assert!(18.4 - intergrated.sum_between(0., 2.) < f32::EPSILON);
+    assert!((18.4 - intergrated.sum_between(0., 2.)).abs() < f32::EPSILON);
+}
+
+#[cfg(feature = "built_in_types")]
+#[test]
+fn nth_antiderivative() {
+    // Builds the initial equation, being 6x + 2
+    let terms: Polynomial<f64> = Polynomial(vec![Term::new(6., 1.), Term::new(2., 0.)]);
+
+    // Intergrating twice gives x^3 + x^2
+    let intergrated = terms.nth_antiderivative(2);
+
+    assert_eq!(intergrated, terms.integrate_self().integrate_self());
+    assert!((12. - intergrated.sum_with_respect_to(&2.)).abs() < f64::EPSILON);
+}
+
+#[cfg(feature = "built_in_types")]
+#[test]
+fn integrate_between_bounds() {
+    // Builds the initial equation, being 3x^2 + 2
+    let terms: Polynomial<f64> = Polynomial(vec![Term::new(3., 2.), Term::new(2., 0.)]);
+
+    // From 0 to 2, x^3 + 2x gives 12, and swapping the bounds flips the sign
+    assert!((terms.integrate(0., 2.) - 12.).abs() < f64::EPSILON);
+    assert!((terms.integrate(2., 0.) + 12.).abs() < f64::EPSILON);
+    assert!((terms.0[0].integrate(0., 2.) - 8.).abs() < f64::EPSILON);
 }
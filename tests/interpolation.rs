@@ -0,0 +1,68 @@
+#[cfg(feature = "built_in_types")]
+use cakcukus::{
+    interpolation::NewtonForm,
+    traits::{Differentiation, TermTrait},
+    Polynomial,
+};
+
+#[cfg(feature = "built_in_types")]
+#[test]
+fn lagrange_and_newton_agree() {
+    let points = [(-2., 5.), (0., -1.), (1., 2.), (3., 0.5)];
+
+    let lagrange: Polynomial<f64> = Polynomial::lagrange(&points);
+    let newton = Polynomial::newton(&points);
+
+    assert_eq!(lagrange.0.len(), newton.0.len());
+    for (a, b) in lagrange.0.iter().zip(newton.0.iter()) {
+        assert!((a.coefficient - b.coefficient).abs() < 1e-12);
+        assert_eq!(a.exponent, b.exponent);
+    }
+    for (x, y) in points.iter() {
+        assert!((lagrange.sum_with_respect_to(x) - y).abs() < 1e-12);
+    }
+}
+
+#[cfg(feature = "built_in_types")]
+#[test]
+fn newton_points_can_be_added() {
+    let f = |x: f64| x.sin();
+    let xs = [0., 0.4, 0.8, 1.2, 1.6];
+
+    let mut newton = NewtonForm::new(&[(xs[0], f(xs[0]))]);
+    for x in xs.iter().skip(1) {
+        newton.add_point(*x, f(*x));
+    }
+    let all_at_once = NewtonForm::new(&xs.iter().map(|x| (*x, f(*x))).collect::<Vec<_>>());
+
+    assert_eq!(newton.nodes(), all_at_once.nodes());
+    for (a, b) in newton.coefficients().iter().zip(all_at_once.coefficients()) {
+        assert!((a - b).abs() < 1e-12);
+    }
+    assert!((newton.sum_with_respect_to(&1.) - f(1.)).abs() < 1e-3);
+    assert!(
+        (newton.sum_with_respect_to(&1.) - newton.to_polynomial().sum_with_respect_to(&1.)).abs()
+            < 1e-12
+    );
+}
+
+#[cfg(feature = "built_in_types")]
+#[test]
+fn hermite_matches_derivatives() {
+    // cos with its value, slope and curvature at 0, and its value
+    // and slope at 1
+    let points = [
+        (0f64, vec![1., 0., -1.]),
+        (1., vec![1f64.cos(), -1f64.sin()]),
+    ];
+
+    let hermite = Polynomial::hermite(&points);
+    let second = hermite.differentiate_self();
+
+    assert!((hermite.sum_with_respect_to(&0.) - 1.).abs() < 1e-12);
+    assert!(hermite.differentiate(&0.).abs() < 1e-12);
+    assert!((second.differentiate(&0.) + 1.).abs() < 1e-12);
+    assert!((hermite.sum_with_respect_to(&1.) - 1f64.cos()).abs() < 1e-12);
+    assert!((hermite.differentiate(&1.) + 1f64.sin()).abs() < 1e-12);
+    assert!((hermite.sum_with_respect_to(&0.5) - 0.5f64.cos()).abs() < 1e-3);
+}
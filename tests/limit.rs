@@ -0,0 +1,95 @@
+#[cfg(feature = "built_in_types")]
+use cakcukus::{
+    limit::{lhopital, Limit},
+    terms, Polynomial, RationalFunction, Term,
+};
+
+#[cfg(feature = "built_in_types")]
+#[test]
+fn polynomials() {
+    let cubic: Polynomial<f64> = terms!(1., 3., -4., 1.);
+    assert_eq!(cubic.limit(f64::INFINITY), Limit::PositiveInfinity);
+    assert_eq!(cubic.limit(f64::NEG_INFINITY), Limit::NegativeInfinity);
+    assert_eq!(cubic.limit(2.), Limit::Finite(0.));
+
+    // 3 + x^-2 approaches 3 at infinity, and blows up at 0
+    let laurent: Polynomial<f64> = terms!(3., 0., 1., -2.);
+    assert_eq!(laurent.limit(f64::INFINITY), Limit::Finite(3.));
+    assert_eq!(laurent.limit(0.), Limit::PositiveInfinity);
+
+    // x^-1 has different signs either side of 0
+    let inverse: Polynomial<f64> = Polynomial(vec![Term::new(1., -1.)]);
+    assert_eq!(inverse.limit(0.), Limit::DoesNotExist);
+}
+
+#[cfg(feature = "built_in_types")]
+#[test]
+fn rational_functions() {
+    // (x^2 - 4) / (x - 2) approaches 4 at x = 2
+    let removable = RationalFunction::new(terms!(1., 2., -4., 0.), terms!(1., 1., -2., 0.));
+    assert_eq!(removable.limit(2.), Limit::Finite(4.));
+    assert_eq!(removable.limit(f64::NEG_INFINITY), Limit::NegativeInfinity);
+
+    // 1 / (x - 1)^2 blows up to infinity from both sides of 1
+    let double_pole = RationalFunction::new(
+        Polynomial(vec![Term::new(1., 0.)]),
+        terms!(1., 2., -2., 1., 1., 0.),
+    );
+    assert_eq!(double_pole.limit(1.), Limit::PositiveInfinity);
+    assert_eq!(double_pole.limit(f64::INFINITY), Limit::Finite(0.));
+
+    // x^3 / (x^3 - x^2), which needs L'Hôpital's rule twice at 0
+    let twice = RationalFunction::new(Polynomial(vec![Term::new(1., 3.)]), terms!(1., 3., -1., 2.));
+    assert_eq!(twice.limit(0.), Limit::Finite(0.));
+    assert_eq!(twice.limit(f64::INFINITY), Limit::Finite(1.));
+}
+
+#[cfg(feature = "built_in_types")]
+#[test]
+fn rounded_singularities() {
+    let close = |limit: Limit<f64>, expected: f64| match limit {
+        Limit::Finite(value) => assert!((value - expected).abs() < 1e-12),
+        other => panic!("expected a finite limit, got {:?}", other),
+    };
+
+    // 0.1 isn't exact in binary, so neither side is exactly 0 there
+    let removable = RationalFunction::new(terms!(1., 2., -0.01, 0.), terms!(1., 1., -0.1, 0.));
+    close(removable.limit(0.1), 0.2);
+
+    let cubic = RationalFunction::new(terms!(1., 3., -0.027, 0.), terms!(1., 1., -0.3, 0.));
+    close(cubic.limit(0.3), 0.27);
+
+    // (x - 0.3)^2 (x + 1) / (x - 0.3)^2
+    let squared = RationalFunction::new(
+        Polynomial(vec![
+            Term::new(1., 3.),
+            Term::new(0.4, 2.),
+            Term::new(-0.51, 1.),
+            Term::new(0.09, 0.),
+        ]),
+        terms!(1., 2., -0.6, 1., 0.09, 0.),
+    );
+    close(squared.limit(0.3), 1.3);
+
+    // A genuine pole near a non-dyadic point is still a pole
+    let pole = RationalFunction::new(
+        Polynomial(vec![Term::new(1., 0.)]),
+        terms!(1., 1., -0.1, 0.),
+    );
+    assert_eq!(pole.limit(0.1), Limit::DoesNotExist);
+}
+
+#[cfg(feature = "built_in_types")]
+#[test]
+fn numerical_lhopital() {
+    use cakcukus::finite_difference::NumericalFunction;
+
+    // sin(x) / x approaches 1 at 0
+    let sin = NumericalFunction::new(|x: f64| x.sin());
+    let x: Polynomial<f64> = Polynomial(vec![Term::new(1., 1.)]);
+
+    match lhopital(&sin, &x, 0., 1e-9) {
+        Limit::Finite(value) => assert!((value - 1.).abs() < 1e-9),
+        other => panic!("expected a finite limit, got {:?}", other),
+    }
+}
@@ -0,0 +1,82 @@
+#[cfg(feature = "built_in_types")]
+use cakcukus::{
+    exact::Rational,
+    matrix::{characteristic_polynomial, minimal_polynomial},
+    terms, Polynomial,
+};
+
+#[cfg(feature = "built_in_types")]
+#[test]
+fn characteristic_polynomial_is_exact() {
+    let r = Rational::from_integer;
+    let matrix = vec![
+        vec![r(2), r(1), r(0)],
+        vec![r(1), r(3), r(1)],
+        vec![r(0), r(1), r(4)],
+    ];
+    // x^3 - tr(A) x^2 + (sum of principal 2x2 minors) x - det(A)
+    let expected = Polynomial(vec![
+        terms!(r(1), r(3)),
+        terms!(r(-9), r(2)),
+        terms!(r(24), r(1)),
+        terms!(r(-18), r(0)),
+    ]);
+    assert_eq!(characteristic_polynomial(&matrix), expected);
+
+    // A companion matrix has its polynomial as characteristic polynomial
+    let p = Polynomial(vec![
+        terms!(r(1), r(5)),
+        terms!(Rational::new(-3, 2), r(3)),
+        terms!(r(7), r(1)),
+        terms!(r(-2), r(0)),
+    ]);
+    assert_eq!(characteristic_polynomial(&p.companion_matrix()), p);
+}
+
+#[cfg(feature = "built_in_types")]
+#[test]
+fn minimal_polynomial_drops_repeated_factors() {
+    let r = Rational::from_integer;
+    // A Jordan block for 2 of size 2, another of size 1, and a 3
+    let matrix = vec![
+        vec![r(2), r(1), r(0), r(0)],
+        vec![r(0), r(2), r(0), r(0)],
+        vec![r(0), r(0), r(2), r(0)],
+        vec![r(0), r(0), r(0), r(3)],
+    ];
+    // (x - 2)^3 (x - 3), and (x - 2)^2 (x - 3)
+    let characteristic = Polynomial(vec![
+        terms!(r(1), r(4)),
+        terms!(r(-9), r(3)),
+        terms!(r(30), r(2)),
+        terms!(r(-44), r(1)),
+        terms!(r(24), r(0)),
+    ]);
+    let minimal = Polynomial(vec![
+        terms!(r(1), r(3)),
+        terms!(r(-7), r(2)),
+        terms!(r(16), r(1)),
+        terms!(r(-12), r(0)),
+    ]);
+    assert_eq!(characteristic_polynomial(&matrix), characteristic);
+    assert_eq!(minimal_polynomial(&matrix), minimal);
+
+    let zero = vec![vec![r(0); 3]; 3];
+    assert_eq!(
+        minimal_polynomial(&zero),
+        Polynomial(vec![terms!(r(1), r(1))])
+    );
+}
+
+#[cfg(all(feature = "built_in_types", feature = "nalgebra"))]
+#[test]
+fn nalgebra_matrices() {
+    use nalgebra::DMatrix;
+
+    // A rotation by 90 degrees, with x^2 + 1 as both polynomials
+    let rotation = DMatrix::from_row_slice(2, 2, &[0., -1., 1., 0.]);
+    let expected: Polynomial<f64> = terms!(1., 2., 1., 0.);
+
+    assert_eq!(characteristic_polynomial(&rotation), expected);
+    assert_eq!(minimal_polynomial(&rotation), expected);
+}
@@ -0,0 +1,131 @@
+#[cfg(feature = "built_in_types")]
+use cakcukus::{MonomialOrder, MultiPolynomial, MultiTerm};
+
+#[cfg(feature = "built_in_types")]
+#[test]
+fn evaluate() {
+    // x^2y + 3yz - 4
+    let p: MultiPolynomial<f64> = MultiPolynomial(vec![
+        MultiTerm::new(1., vec![2, 1]),
+        MultiTerm::new(3., vec![0, 1, 1]),
+        MultiTerm::new(-4., vec![]),
+    ]);
+
+    // 4 * 3 + 3 * 3 * 5 - 4 = 53
+    assert!((53. - p.evaluate(&[2., 3., 5.])).abs() < f64::EPSILON);
+    assert_eq!(p.total_degree(), 3);
+    assert_eq!(p.variables(), 3);
+}
+
+#[cfg(feature = "built_in_types")]
+#[test]
+fn partial_derivative() {
+    // x^2y + 3yz - 4
+    let p: MultiPolynomial<f64> = MultiPolynomial(vec![
+        MultiTerm::new(1., vec![2, 1]),
+        MultiTerm::new(3., vec![0, 1, 1]),
+        MultiTerm::new(-4., vec![]),
+    ]);
+
+    let mut dy = p.partial_derivative(1);
+    dy.simplify();
+
+    // x^2 + 3z
+    assert_eq!(
+        dy,
+        MultiPolynomial(vec![
+            MultiTerm::new(1., vec![2]),
+            MultiTerm::new(3., vec![0, 0, 1]),
+        ])
+    );
+
+    let mut dx = p.partial_derivative(0);
+    dx.simplify();
+    assert_eq!(dx, MultiPolynomial(vec![MultiTerm::new(2., vec![1, 1])]));
+
+    // Intergrating undoes the derivative, up to the dropped constant
+    let mut integrated = dy.integrate_with_respect_to(1);
+    integrated.simplify();
+    assert_eq!(integrated, {
+        let mut p = p.clone() - MultiPolynomial(vec![MultiTerm::new(-4., vec![])]);
+        p.simplify();
+        p
+    });
+}
+
+#[cfg(feature = "built_in_types")]
+#[test]
+fn orderings() {
+    let a = [1, 2, 0]; // xy^2
+    let b = [2, 0, 1]; // x^2z
+    let c = [0, 0, 4]; // z^4
+
+    assert!(MonomialOrder::Lex.compare(&b, &a).is_gt());
+    assert!(MonomialOrder::Lex.compare(&a, &c).is_gt());
+    assert!(MonomialOrder::GradedLex.compare(&c, &b).is_gt());
+    assert!(MonomialOrder::GradedLex.compare(&b, &a).is_gt());
+    // Both have degree 3, and b has the smaller power of z
+    assert!(MonomialOrder::GradedReverseLex.compare(&a, &b).is_gt());
+
+    let mut p = MultiPolynomial(vec![
+        MultiTerm::new(1, a.to_vec()),
+        MultiTerm::new(1, b.to_vec()),
+        MultiTerm::new(1, c.to_vec()),
+    ]);
+    p.simplify_by(MonomialOrder::Lex);
+    assert_eq!(p.0[0].exponents, vec![2, 0, 1]);
+    assert_eq!(
+        p.leading_term(MonomialOrder::GradedLex).unwrap().exponents,
+        vec![0, 0, 4]
+    );
+}
+
+#[cfg(feature = "built_in_types")]
+#[test]
+fn gradient_and_hessian() {
+    // x^3 + 2xy + y^2
+    let p: MultiPolynomial<f64> = MultiPolynomial(vec![
+        MultiTerm::new(1., vec![3]),
+        MultiTerm::new(2., vec![1, 1]),
+        MultiTerm::new(1., vec![0, 2]),
+    ]);
+
+    // [3x^2 + 2y, 2x + 2y]
+    assert_eq!(p.gradient_at(&[1., 2.]), vec![7., 6.]);
+    let gradient = p.gradient();
+    assert_eq!(gradient.len(), 2);
+    assert_eq!(gradient[1].evaluate(&[3., -1.]), 4.);
+
+    // [[6x, 2], [2, 2]]
+    let hessian = p.hessian();
+    assert_eq!(hessian[0][1], hessian[1][0]);
+    assert_eq!(p.hessian_at(&[2., 0.]), vec![vec![12., 2.], vec![2., 2.]]);
+}
+
+#[cfg(feature = "built_in_types")]
+#[test]
+fn jacobian() {
+    use cakcukus::{jacobian, jacobian_at};
+
+    // f = (xy, x + z^2)
+    let functions: Vec<MultiPolynomial<f64>> = vec![
+        MultiPolynomial(vec![MultiTerm::new(1., vec![1, 1])]),
+        MultiPolynomial(vec![
+            MultiTerm::new(1., vec![1]),
+            MultiTerm::new(1., vec![0, 0, 2]),
+        ]),
+    ];
+
+    let symbolic = jacobian(&functions);
+    assert_eq!(symbolic.len(), 2);
+    assert_eq!(symbolic[0].len(), 3);
+
+    let point = [2., 3., 4.];
+    let evaluated = jacobian_at(&functions, &point);
+    assert_eq!(evaluated, vec![vec![3., 2., 0.], vec![1., 0., 8.]]);
+    for (row, symbolic_row) in evaluated.iter().zip(symbolic.iter()) {
+        for (value, derivative) in row.iter().zip(symbolic_row.iter()) {
+            assert_eq!(*value, derivative.evaluate(&point));
+        }
+    }
+}
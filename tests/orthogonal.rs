@@ -0,0 +1,117 @@
+#[cfg(feature = "built_in_types")]
+use cakcukus::{
+    orthogonal::Family, quadrature::gauss_legendre_nodes, terms, traits::TermTrait, Polynomial,
+    Term,
+};
+
+#[cfg(feature = "built_in_types")]
+#[test]
+fn polynomials_match_recurrence() {
+    let families = [
+        Family::ChebyshevFirst,
+        Family::ChebyshevSecond,
+        Family::Legendre,
+        Family::Hermite,
+        Family::Laguerre { alpha: 0.5 },
+        Family::Jacobi {
+            alpha: 0.5,
+            beta: -0.3,
+        },
+    ];
+    for family in families.iter() {
+        for n in 0..7 {
+            let polynomial = family.polynomial(n);
+            for x in [-0.9, -0.2, 0.4, 0.8].iter() {
+                let expected: f64 = family.evaluate(n, *x);
+                assert!((polynomial.sum_with_respect_to(x) - expected).abs() < 1e-9);
+            }
+        }
+    }
+
+    // Closed forms
+    let x = 0.3f64;
+    assert!((Family::ChebyshevFirst.evaluate(5, x) - (5. * x.acos()).cos()).abs() < 1e-12);
+    assert!(
+        (Family::ChebyshevSecond.evaluate(5, x) - (6. * x.acos()).sin() / x.acos().sin()).abs()
+            < 1e-12
+    );
+    assert!((Family::Legendre.evaluate(2, x) - (3. * x * x - 1.) / 2.).abs() < 1e-12);
+    assert!((Family::Hermite.evaluate(3, x) - (8. * x.powi(3) - 12. * x)).abs() < 1e-12);
+    assert!(
+        (Family::Laguerre { alpha: 0. }.evaluate(2, x) - (x * x - 4. * x + 2.) / 2.).abs() < 1e-12
+    );
+    // Jacobi with alpha = beta = 0 is Legendre
+    let jacobi = Family::Jacobi {
+        alpha: 0.,
+        beta: 0.,
+    };
+    assert!((jacobi.evaluate(4, x) - Family::Legendre.evaluate(4, x)).abs() < 1e-12);
+}
+
+#[cfg(feature = "built_in_types")]
+#[test]
+fn gauss_nodes() {
+    // Golub-Welsch agrees with the Newton iteration for Legendre
+    let golub_welsch = Family::<f64>::Legendre.gauss_nodes(8);
+    let newton = gauss_legendre_nodes::<f64>(8);
+    let mut newton: Vec<_> = newton.into_iter().collect();
+    newton.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+    for ((x, w), (y, v)) in golub_welsch.iter().zip(newton.iter()) {
+        assert!((x - y).abs() < 1e-12);
+        assert!((w - v).abs() < 1e-12);
+    }
+
+    // Chebyshev nodes are known in closed form, with equal weights
+    let chebyshev = Family::<f64>::ChebyshevFirst.gauss_nodes(5);
+    for (k, (node, weight)) in chebyshev.iter().rev().enumerate() {
+        let expected = (std::f64::consts::PI * (2 * k + 1) as f64 / 10.).cos();
+        assert!((node - expected).abs() < 1e-12);
+        assert!((weight - std::f64::consts::PI / 5.).abs() < 1e-12);
+    }
+
+    // The integral of x^3 e^(-x) over [0, inf) is 3! = 6
+    let laguerre = Family::Laguerre { alpha: 0. };
+    assert!((laguerre.integrate(|x: f64| x.powi(3), 2) - 6.).abs() < 1e-10);
+    // The integral of (1 - x)(1 + x)^2 over [-1, 1] is 4/3
+    let jacobi = Family::Jacobi {
+        alpha: 1.,
+        beta: 2.,
+    };
+    assert!((jacobi.integrate(|_: f64| 1., 3) - 4. / 3.).abs() < 1e-10);
+}
+
+#[cfg(feature = "built_in_types")]
+#[test]
+fn chebyshev_basis_round_trip() {
+    // 3x^4 - x^3 + 2x - 5
+    let p: Polynomial<f64> = terms!(3., 4., -1., 3., 2., 1., -5., 0.);
+
+    let chebyshev = p.to_chebyshev();
+    assert_eq!(chebyshev.len(), 5);
+    for x in [-0.7, 0.1, 0.9].iter() {
+        let sum: f64 = chebyshev
+            .iter()
+            .enumerate()
+            .map(|(n, c)| c * Family::ChebyshevFirst.evaluate(n, *x))
+            .sum();
+        assert!((sum - p.sum_with_respect_to(x)).abs() < 1e-12);
+    }
+
+    let back = Polynomial::from_chebyshev(&chebyshev);
+    for (a, b) in back.0.iter().zip(p.0.iter()) {
+        assert!((a.coefficient - b.coefficient).abs() < 1e-12);
+        assert_eq!(a.exponent, b.exponent);
+    }
+
+    // x^3 = (3 T1 + T3) / 4
+    let cube: Polynomial<f64> = Polynomial(vec![Term::new(1., 3.)]);
+    assert_eq!(cube.to_chebyshev(), vec![0., 0.75, 0., 0.25]);
+}
+
+#[cfg(feature = "built_in_types")]
+#[test]
+#[should_panic(expected = "The Gauss nodes should have converged")]
+fn gauss_nodes_outside_parameter_range() {
+    // x^-2 e^(-x) can't be integrated at 0, so has no Gauss rule
+    Family::Laguerre { alpha: -2f64 }.gauss_nodes(4);
+}
@@ -0,0 +1,44 @@
+#[cfg(feature = "built_in_types")]
+use cakcukus::{pade::PadeError, traits::TermTrait, PowerSeries};
+
+#[cfg(feature = "built_in_types")]
+#[test]
+fn exp() {
+    // e^x's [2/2] approximant is (1 + x/2 + x^2/12) / (1 - x/2 + x^2/12)
+    let exp = PowerSeries::new(vec![0., 1., 0., 0., 0.]).exp();
+    let approximant = exp.pade(2, 2).unwrap();
+
+    for x in [-1f64, 0.5, 2.].iter() {
+        let expected = (1. + x / 2. + x * x / 12.) / (1. - x / 2. + x * x / 12.);
+        assert!((approximant.sum_with_respect_to(x) - expected).abs() < 1e-12);
+    }
+    // Better than the partial sum with the same coefficients
+    assert!(
+        (approximant.sum_with_respect_to(&-3.) - (-3f64).exp()).abs()
+            < (exp.sum_with_respect_to(&-3.) - (-3f64).exp()).abs()
+    );
+}
+
+#[cfg(feature = "built_in_types")]
+#[test]
+fn tan() {
+    // tan(x) = x + x^3 / 3 + 2x^5 / 15 + ..., with the [3/2]
+    // approximant (15x - x^3) / (15 - 6x^2)
+    let tan = PowerSeries::new(vec![0., 1., 0., 1. / 3., 0., 2. / 15.]);
+    let approximant = tan.pade(3, 2).unwrap();
+
+    let x = 1.2f64;
+    let expected = (15. * x - x.powi(3)) / (15. - 6. * x * x);
+    assert!((approximant.sum_with_respect_to(&x) - expected).abs() < 1e-12);
+    assert!((approximant.sum_with_respect_to(&x) - x.tan()).abs() < 0.05);
+}
+
+#[cfg(feature = "built_in_types")]
+#[test]
+fn degenerate() {
+    let even = PowerSeries::new(vec![1., 0., 1., 0.]);
+
+    assert_eq!(even.pade(1, 1), Err(PadeError::Singular));
+    assert_eq!(even.pade(2, 2), Err(PadeError::InsufficientCoefficients));
+    assert!(even.pade(2, 1).is_ok());
+}
@@ -0,0 +1,75 @@
+#[cfg(feature = "built_in_types")]
+use cakcukus::{
+    finite_difference::NumericalFunction,
+    piecewise::Piecewise,
+    terms,
+    traits::{Differentiation, Integration, TermTrait},
+    Polynomial, Term,
+};
+#[cfg(feature = "built_in_types")]
+use core::ops::Bound;
+
+#[cfg(feature = "built_in_types")]
+#[test]
+fn evaluation_and_intervals() {
+    let square: Polynomial<f64> = Polynomial(vec![Term::new(1., 2.)]);
+    let line: Polynomial<f64> = terms!(3., 1., 1., 0.);
+    let f = Piecewise::new()
+        .with_piece(..0., square)
+        .with_piece(0.0..=2., line.clone())
+        .with_piece((Bound::Excluded(2.), Bound::Unbounded), line);
+
+    assert_eq!(f.evaluate(&-3.), Some(9.));
+    assert_eq!(f.evaluate(&0.), Some(1.));
+    assert_eq!(f.evaluate(&5.), Some(16.));
+    assert_eq!(f.breakpoints(), vec![0., 2.]);
+    assert_eq!(f.differentiate(&-1.), -2.);
+    assert_eq!(f.differentiate_self().sum_with_respect_to(&1.), 3.);
+
+    let partial = Piecewise::new().with_piece(0.0..1., Polynomial::<f64>::new());
+    assert_eq!(partial.evaluate(&1.), None);
+}
+
+#[cfg(feature = "built_in_types")]
+#[test]
+fn integrates_across_breakpoints() {
+    // x^2 for x < 0, 3x + 1 otherwise
+    let square: Polynomial<f64> = Polynomial(vec![Term::new(1., 2.)]);
+    let line: Polynomial<f64> = terms!(3., 1., 1., 0.);
+    let f = Piecewise::new()
+        .with_piece(..0., square)
+        .with_piece(0.0.., line);
+
+    // 8/3 from the square, then 6 + 2 from the line
+    let expected = 8. / 3. + 8.;
+    assert!((f.integrate(-2., 2.) - expected).abs() < 1e-12);
+    assert!((f.integrate(2., -2.) + expected).abs() < 1e-12);
+    assert!((f.integrate(-2., -1.) - 7. / 3.).abs() < 1e-12);
+}
+
+#[cfg(feature = "built_in_types")]
+#[test]
+fn joins() {
+    let square: Polynomial<f64> = Polynomial(vec![Term::new(1., 2.)]);
+    let line: Polynomial<f64> = terms!(3., 1., 1., 0.);
+    let jump = Piecewise::new()
+        .with_piece(..0., square.clone())
+        .with_piece(0.0.., line);
+    assert!(!jump.is_continuous(1e-12));
+
+    let negated: Polynomial<f64> = Polynomial(vec![Term::new(-1., 2.)]);
+    let smooth = Piecewise::new()
+        .with_piece(..0., square)
+        .with_piece(0.0.., negated);
+    assert!(smooth.is_continuous(1e-12));
+    assert!(smooth.is_differentiable(1e-12));
+
+    // |x| is continuous, but has a corner at 0, which numerical
+    // pieces can show too
+    let absolute = Piecewise::new()
+        .with_piece(..0., NumericalFunction::new((|x| -x) as fn(f64) -> f64))
+        .with_piece(0.0.., NumericalFunction::new((|x| x) as fn(f64) -> f64));
+    assert!(absolute.is_continuous(1e-12));
+    assert!(!absolute.is_differentiable(1e-6));
+    assert!((absolute.integrate(-1., 2.) - 2.5).abs() < 1e-6);
+}
@@ -1,5 +1,7 @@
 #[cfg(feature = "built_in_types")]
-use cakcukus::{terms, Polynomial};
+use cakcukus::{exact::Rational, terms, Polynomial};
+#[cfg(feature = "built_in_types")]
+use num_traits::Pow;
 
 #[cfg(feature = "built_in_types")]
 #[test]
@@ -130,3 +132,110 @@ fn simplify_bench() {
         polynomial.0.len()
     );
 }
+
+#[cfg(feature = "built_in_types")]
+#[test]
+fn pow_matches_repeated_multiplication() {
+    let r = Rational::from_integer;
+    let bases = vec![
+        Polynomial(vec![terms!(Rational::new(2, 3), r(3))]),
+        terms!(r(1), r(1), r(-2), r(0)),
+        Polynomial(vec![
+            terms!(r(1), r(2)),
+            terms!(r(-1), r(1)),
+            terms!(r(3), r(0)),
+        ]),
+        Polynomial(vec![
+            terms!(r(2), r(5)),
+            terms!(r(1), r(3)),
+            terms!(r(-1), r(2)),
+            terms!(r(1), r(1)),
+            terms!(r(4), r(0)),
+        ]),
+        // Exponents a whole number apart, though not whole themselves
+        Polynomial(vec![
+            terms!(r(1), Rational::new(7, 2)),
+            terms!(r(-2), Rational::new(3, 2)),
+            terms!(Rational::new(1, 2), Rational::new(1, 2)),
+            terms!(r(5), Rational::new(-1, 2)),
+        ]),
+    ];
+    for base in bases {
+        let mut expected = Polynomial(vec![terms!(r(1), r(0))]);
+        for n in 0..7u32 {
+            let mut powered = base.clone().pow(n);
+            powered.simplify();
+            assert_eq!(powered, expected);
+            expected *= base.clone();
+            expected.simplify();
+            expected.0.retain(|term| term.coefficient != r(0));
+        }
+    }
+}
+
+#[cfg(feature = "built_in_types")]
+#[test]
+fn pow_of_zero_and_cancelling_bases() {
+    let zero: Polynomial<f64> = Polynomial::new();
+    assert_eq!(zero.clone().pow(0u32), Polynomial(vec![terms!(1., 0.)]));
+    assert_eq!(zero.pow(3u32), Polynomial::new());
+
+    // (x - x + 2)^3 is 8, with the cancelled terms dropped
+    let cancelling: Polynomial<f64> = terms!(1., 1., -1., 1.) + terms!(2., 0.);
+    assert_eq!(cancelling.pow(3u32), Polynomial(vec![terms!(8., 0.)]));
+}
+
+#[cfg(feature = "built_in_types")]
+#[test]
+#[ignore]
+fn pow_bench() {
+    use simple_stopwatch::Stopwatch;
+
+    // Plain repeated squaring, to compare the expansions against
+    fn squaring(base: &Polynomial<f64>, mut n: u32) -> Polynomial<f64> {
+        let mut result = Polynomial(vec![terms!(1., 0.)]);
+        let mut square = base.clone();
+        while n > 0 {
+            if n & 1 == 1 {
+                result *= square.clone();
+                result.simplify();
+            }
+            n >>= 1;
+            if n > 0 {
+                square *= square.clone();
+                square.simplify();
+            }
+        }
+        result
+    }
+
+    // The binomial and multinomial expansions, against squaring
+    let bases: Vec<Polynomial<f64>> = vec![
+        terms!(1., 1., 1., 0.),
+        terms!(2., 3., -0.5, 0.),
+        terms!(1., 1.5, 1., -1.),
+        Polynomial(vec![terms!(1., 2.), terms!(1., 1.), terms!(1., 0.)]),
+        Polynomial(vec![
+            terms!(0.3, 3.),
+            terms!(-1.7, 2.),
+            terms!(2.2, 1.),
+            terms!(-0.9, 0.),
+        ]),
+    ];
+    for base in bases {
+        let mut sw = Stopwatch::start_new();
+        let expanded = base.clone().pow(120u32);
+        let expanded_ms = sw.ms();
+        sw.restart();
+        let squared = squaring(&base, 120);
+        let squared_ms = sw.ms();
+        println!(
+            "{} terms ^120: expansion {}ms, squaring {}ms, {} terms",
+            base.0.len(),
+            expanded_ms,
+            squared_ms,
+            expanded.0.len()
+        );
+        assert_eq!(expanded.0.len(), squared.0.len());
+    }
+}
@@ -0,0 +1,86 @@
+#[cfg(feature = "built_in_types")]
+use cakcukus::{
+    traits::{Differentiation, Integration, TermTrait},
+    PowerSeries,
+};
+
+#[cfg(feature = "built_in_types")]
+fn assert_close(series: &PowerSeries<f64>, expected: &[f64]) {
+    assert_eq!(series.precision(), expected.len());
+    for (a, b) in series.coefficients().iter().zip(expected.iter()) {
+        assert!((a - b).abs() < 1e-12, "{:?} != {:?}", series, expected);
+    }
+}
+
+#[cfg(feature = "built_in_types")]
+#[test]
+fn arithmetic() {
+    // 1 / (1 - x) = 1 + x + x^2 + ...
+    let geometric = PowerSeries::new(vec![1., -1., 0., 0., 0.])
+        .reciprocal()
+        .unwrap();
+    assert_close(&geometric, &[1., 1., 1., 1., 1.]);
+
+    // Squaring it gives 1 + 2x + 3x^2 + ..., truncated to the precision
+    assert_close(
+        &(geometric.clone() * geometric.clone()),
+        &[1., 2., 3., 4., 5.],
+    );
+
+    // Mixing precisions keeps the smaller one
+    let short = PowerSeries::new(vec![1., 1.]);
+    assert_eq!((geometric.clone() + short.clone()).precision(), 2);
+    assert_close(&(geometric / short), &[1., 0.]);
+
+    assert_eq!(PowerSeries::new(vec![0., 1.]).reciprocal(), None);
+}
+
+#[cfg(feature = "built_in_types")]
+#[test]
+fn elementary_functions() {
+    let x = PowerSeries::x(6);
+
+    // e^x = 1 + x + x^2 / 2 + ...
+    let exp = x.exp();
+    assert_close(&exp, &[1., 1., 0.5, 1. / 6., 1. / 24., 1. / 120.]);
+
+    // ln(e^x) = x
+    assert_close(&exp.ln().unwrap(), &[0., 1., 0., 0., 0., 0.]);
+
+    // sqrt(1 + x) squared is 1 + x
+    let root = PowerSeries::new(vec![1., 1., 0., 0., 0., 0.])
+        .sqrt()
+        .unwrap();
+    assert_close(&(root.clone() * root), &[1., 1., 0., 0., 0., 0.]);
+
+    // e^x - 1 reverts to ln(1 + x) = x - x^2 / 2 + x^3 / 3 - ...
+    let shifted = exp - PowerSeries::new(vec![1., 0., 0., 0., 0., 0.]);
+    assert_close(
+        &shifted.reversion().unwrap(),
+        &[0., 1., -0.5, 1. / 3., -0.25, 0.2],
+    );
+
+    // Composing e^x - 1 with its inverse gives back x
+    let inverse = shifted.reversion().unwrap();
+    assert_close(
+        &shifted.compose(&inverse).unwrap(),
+        &[0., 1., 0., 0., 0., 0.],
+    );
+}
+
+#[cfg(feature = "built_in_types")]
+#[test]
+fn calculus() {
+    // 1 + 2x + 3x^2
+    let series = PowerSeries::new(vec![1., 2., 3.]);
+
+    assert_close(&series.differentiate_self(), &[2., 6.]);
+    assert_close(&series.integrate_self(), &[0., 1., 1., 1.]);
+    assert!((series.integrate(0., 1.) - 3.).abs() < 1e-12);
+    assert!((series.sum_with_respect_to(&2.) - 17.).abs() < 1e-12);
+    assert!((series.differentiate(&1.) - 8.).abs() < 1e-12);
+    assert_eq!(
+        series.to_polynomial(),
+        cakcukus::terms!(3., 2., 2., 1., 1., 0.)
+    );
+}
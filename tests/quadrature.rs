@@ -0,0 +1,77 @@
+use cakcukus::quadrature::{
+    gauss_kronrod, gauss_legendre, gauss_legendre_nodes, romberg, simpson, trapezoid,
+    QuadratureError,
+};
+
+#[test]
+fn fixed_rules() {
+    let f = |x: f64| x.exp();
+    let exact = 1f64.exp() - 1.;
+
+    assert!((trapezoid(f, 0., 1., 1000) - exact).abs() < 1e-6);
+    assert!((simpson(f, 0., 1., 100) - exact).abs() < 1e-9);
+    assert!((gauss_legendre(f, 0., 1., 10) - exact).abs() < 1e-14);
+
+    // The rule with n points is exact for degree 2n - 1
+    let quintic = |x: f64| x.powi(5) - 3. * x * x;
+    assert!((gauss_legendre(quintic, -1., 2., 3) - 1.5).abs() < 1e-12);
+
+    let weights: f64 = gauss_legendre_nodes::<f64>(7).iter().map(|(_, w)| w).sum();
+    assert!((weights - 2.).abs() < 1e-14);
+}
+
+#[test]
+#[should_panic(expected = "gauss_legendre needs at least one point")]
+fn gauss_legendre_without_points() {
+    gauss_legendre(|x: f64| x, 0., 1., 0);
+}
+
+#[test]
+fn adaptive_rules() {
+    let f = |x: f64| 1. / (1. + x * x);
+    let exact = core::f64::consts::FRAC_PI_4;
+
+    let estimate = romberg(f, 0., 1., 1e-12, 20).unwrap();
+    assert!((estimate.value - exact).abs() < 1e-11);
+
+    let estimate = gauss_kronrod(f, 0., 1., 1e-12, 100).unwrap();
+    assert!((estimate.value - exact).abs() < 1e-12);
+    assert!(estimate.error <= 1e-12);
+
+    // A kink needs many subdivisions, so a tight limit fails
+    match gauss_kronrod(|x: f64| x.abs().sqrt(), -1., 1., 1e-14, 2) {
+        Err(QuadratureError::NotConverged(estimate)) => {
+            assert!((estimate.value - 4. / 3.).abs() < 1e-2)
+        }
+        Ok(_) => panic!("should not have converged"),
+        Err(QuadratureError::Divergent) => panic!("should not have diverged"),
+        Err(QuadratureError::OutsideDomain) => panic!("should have stayed in the domain"),
+    }
+}
+
+#[cfg(feature = "built_in_types")]
+#[test]
+fn term_trait_implementors() {
+    use cakcukus::{
+        quadrature::NumericalIntegration,
+        terms,
+        traits::{Integration, TermTrait},
+        Polynomial,
+    };
+
+    // 3x^2 - 2x + 1
+    let polynomial: Polynomial<f64> = terms!(3., 2., -2., 1., 1., 0.);
+    let exact = polynomial.integrate_self().sum_between(-1., 2.);
+
+    assert!((polynomial.integrate_simpson(-1., 2., 2) - exact).abs() < 1e-12);
+    assert!((polynomial.integrate_gauss_legendre(-1., 2., 2) - exact).abs() < 1e-12);
+    assert!(
+        (polynomial
+            .integrate_adaptive(-1., 2., 1e-12, 10)
+            .unwrap()
+            .value
+            - exact)
+            .abs()
+            < 1e-12
+    );
+}
@@ -0,0 +1,80 @@
+#[cfg(feature = "built_in_types")]
+use cakcukus::{
+    chebyshev::Chebyshev,
+    remez::{remez, Minimax},
+    terms,
+    traits::TermTrait,
+    Polynomial,
+};
+
+#[cfg(feature = "built_in_types")]
+#[test]
+fn absolute_value() {
+    // The best quadratic for |x| on [-1, 1] is x^2 + 1/8
+    let minimax = remez(|x: f64| x.abs(), -1., 1., 2).unwrap();
+
+    assert!((minimax.error - 0.125).abs() < 1e-6);
+    for x in [-0.8, -0.3, 0., 0.5, 1.].iter() {
+        let expected = x * x + 0.125;
+        assert!((minimax.polynomial.sum_with_respect_to(x) - expected).abs() < 1e-6);
+    }
+}
+
+#[cfg(feature = "built_in_types")]
+#[test]
+fn equioscillates() {
+    let f = |x: f64| x.sin() * x.exp();
+    let minimax = remez(f, 0., 2., 5).unwrap();
+
+    assert_eq!(minimax.reference.len(), 7);
+    let errors: Vec<f64> = minimax
+        .reference
+        .iter()
+        .map(|x| f(*x) - minimax.polynomial.sum_with_respect_to(x))
+        .collect();
+    for pair in errors.windows(2) {
+        assert!(pair[0] * pair[1] < 0.);
+        assert!((pair[0].abs() - minimax.error).abs() < 1e-6 * minimax.error);
+    }
+
+    // Better than interpolating at the Chebyshev nodes
+    let interpolant = Chebyshev::new(f, 0., 2., 5);
+    let worst = (0..=200)
+        .map(|i| {
+            let x = i as f64 / 100.;
+            (f(x) - interpolant.sum_with_respect_to(&x)).abs()
+        })
+        .fold(0f64, f64::max);
+    assert!(minimax.error < worst);
+}
+
+#[cfg(feature = "built_in_types")]
+#[test]
+fn generates_horner_source() {
+    let minimax = remez(|x: f64| x * x * x - 0.5, -1., 1., 3).unwrap();
+    let source = minimax.to_rust("cube");
+
+    assert!(source.starts_with("/// Minimax approximation"));
+    assert!(source.contains("pub fn cube(x: f64) -> f64 {\n    let mut y = "));
+    assert_eq!(source.matches("y = y * x").count(), 3);
+    assert!(source.contains("y = y * x - 0.5"));
+    assert!(source.ends_with("    y\n}\n"));
+
+    // A constant needs no accumulator
+    let constant = Minimax {
+        polynomial: Polynomial(vec![terms!(2.5, 0.)]),
+        error: 0.,
+        reference: vec![0., 1.],
+    };
+    assert!(constant.to_rust("constant").ends_with("{\n    2.5\n}\n"));
+
+    // Values without a literal use the named constants
+    let overflowed = Minimax {
+        polynomial: Polynomial(vec![terms!(f64::NEG_INFINITY, 1.), terms!(f64::NAN, 0.)]),
+        error: f64::INFINITY,
+        reference: vec![0., 0.5, 1.],
+    };
+    let source = overflowed.to_rust("overflowed");
+    assert!(source.contains("let mut y = f64::NEG_INFINITY;"));
+    assert!(source.contains("y = y * x + f64::NAN;") || source.contains("y = y * x - f64::NAN;"));
+}
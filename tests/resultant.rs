@@ -0,0 +1,111 @@
+#[cfg(feature = "built_in_types")]
+use cakcukus::{
+    exact::Rational,
+    resultant::{discriminant, resultant, sylvester_matrix},
+    terms, Polynomial,
+};
+
+/// Returns the determinant of `matrix` by Gaussian elimination
+#[cfg(feature = "built_in_types")]
+fn determinant(mut matrix: Vec<Vec<Rational>>) -> Rational {
+    let n = matrix.len();
+    let mut result = Rational::from_integer(1);
+    for column in 0..n {
+        let pivot = match (column..n).find(|&row| matrix[row][column] != Rational::from_integer(0))
+        {
+            Some(pivot) => pivot,
+            None => return Rational::from_integer(0),
+        };
+        if pivot != column {
+            matrix.swap(pivot, column);
+            result = -result;
+        }
+        result = result * matrix[column][column];
+        for row in column + 1..n {
+            let factor = matrix[row][column] / matrix[column][column];
+            let pivot_row = matrix[column].clone();
+            for (value, above) in matrix[row].iter_mut().zip(pivot_row).skip(column) {
+                *value = *value - factor * above;
+            }
+        }
+    }
+    result
+}
+
+#[cfg(feature = "built_in_types")]
+#[test]
+fn resultant_is_the_sylvester_determinant() {
+    let r = Rational::from_integer;
+    let p = Polynomial(vec![
+        terms!(r(3), r(5)),
+        terms!(r(-1), r(4)),
+        terms!(r(2), r(2)),
+        terms!(Rational::new(1, 2), r(1)),
+        terms!(r(-4), r(0)),
+    ]);
+    let q = Polynomial(vec![
+        terms!(r(2), r(4)),
+        terms!(r(1), r(3)),
+        terms!(r(-3), r(1)),
+        terms!(r(5), r(0)),
+    ]);
+    let cubic = Polynomial(vec![terms!(r(1), r(3)), terms!(r(-2), r(0))]);
+
+    for (a, b) in [(&p, &q), (&q, &p), (&p, &cubic), (&cubic, &q)].iter() {
+        let matrix = sylvester_matrix(a, b);
+        assert_eq!(resultant(a, b), determinant(matrix));
+    }
+    // Swapping two odd degree polynomials flips the sign
+    assert_eq!(resultant(&p, &cubic), -resultant(&cubic, &p));
+}
+
+#[cfg(feature = "built_in_types")]
+#[test]
+fn shared_roots_and_constants() {
+    let r = Rational::from_integer;
+    // (x - 2)(x + 1)(x - 3) and (x - 3)(x^2 + 1)
+    let a = Polynomial(vec![
+        terms!(r(1), r(3)),
+        terms!(r(-4), r(2)),
+        terms!(r(1), r(1)),
+        terms!(r(6), r(0)),
+    ]);
+    let b = Polynomial(vec![
+        terms!(r(1), r(3)),
+        terms!(r(-3), r(2)),
+        terms!(r(1), r(1)),
+        terms!(r(-3), r(0)),
+    ]);
+    assert_eq!(a.resultant(&b), r(0));
+
+    let constant = Polynomial(vec![terms!(r(5), r(0))]);
+    assert_eq!(a.resultant(&constant), r(125));
+    assert_eq!(constant.resultant(&constant), r(1));
+    assert_eq!(a.resultant(&Polynomial::new()), r(0));
+}
+
+#[cfg(feature = "built_in_types")]
+#[test]
+fn discriminants() {
+    let r = Rational::from_integer;
+    // b^2 - 4ac for 2x^2 + 3x - 5
+    let quadratic = Polynomial(vec![
+        terms!(r(2), r(2)),
+        terms!(r(3), r(1)),
+        terms!(r(-5), r(0)),
+    ]);
+    assert_eq!(discriminant(&quadratic), r(49));
+
+    // -4p^3 - 27q^2 for x^3 + px + q
+    let (p, q) = (Rational::new(-7, 2), r(3));
+    let cubic = Polynomial(vec![terms!(r(1), r(3)), terms!(p, r(1)), terms!(q, r(0))]);
+    assert_eq!(cubic.discriminant(), r(-4) * p * p * p - r(27) * q * q);
+
+    // x^4 - 2x^2 + 1 = (x^2 - 1)^2
+    let square = Polynomial(vec![
+        terms!(r(1), r(4)),
+        terms!(r(-2), r(2)),
+        terms!(r(1), r(0)),
+    ]);
+    assert_eq!(square.discriminant(), r(0));
+}
@@ -0,0 +1,99 @@
+#[cfg(feature = "built_in_types")]
+use cakcukus::{exact::Rational, terms, Polynomial};
+
+#[cfg(all(feature = "built_in_types", feature = "nalgebra"))]
+use cakcukus::{
+    orthogonal::Family,
+    traits::{Differentiation, TermTrait},
+};
+
+/// Polishes `x` towards a root of `p` with Newton's method
+#[cfg(all(feature = "built_in_types", feature = "nalgebra"))]
+fn newton(p: &Polynomial<f64>, mut x: f64) -> f64 {
+    for _ in 0..50 {
+        let step = p.sum_with_respect_to(&x) / p.differentiate(&x);
+        x -= step;
+        if step.abs() <= 1e-15 * x.abs().max(1.) {
+            break;
+        }
+    }
+    x
+}
+
+#[cfg(feature = "built_in_types")]
+#[test]
+fn companion_matrix() {
+    let r = Rational::from_integer;
+    // 3x^3 + 6x + 12, with no x^2 term
+    let p = Polynomial(vec![
+        terms!(r(3), r(3)),
+        terms!(r(6), r(1)),
+        terms!(r(12), r(0)),
+    ]);
+
+    assert_eq!(
+        p.companion_matrix(),
+        vec![
+            vec![r(0), r(0), r(-4)],
+            vec![r(1), r(0), r(-2)],
+            vec![r(0), r(1), r(0)],
+        ]
+    );
+}
+
+#[cfg(all(feature = "built_in_types", feature = "nalgebra"))]
+#[test]
+fn known_roots_with_multiplicities() {
+    // x^2 (x - 1)(x - 2)(x - 3)(x^2 + 1)
+    let p: Polynomial<f64> = Polynomial(vec![
+        terms!(1., 7.),
+        terms!(-6., 6.),
+        terms!(12., 5.),
+        terms!(-12., 4.),
+        terms!(11., 3.),
+        terms!(-6., 2.),
+    ]);
+    let roots = p.roots().unwrap();
+    let expected = [
+        (0., 0.),
+        (0., 0.),
+        (0., -1.),
+        (0., 1.),
+        (1., 0.),
+        (2., 0.),
+        (3., 0.),
+    ];
+
+    assert_eq!(roots.len(), expected.len());
+    for (root, (re, im)) in roots.iter().zip(expected.iter()) {
+        assert!((root.re - re).abs() < 1e-10 && (root.im - im).abs() < 1e-10);
+    }
+}
+
+#[cfg(all(feature = "built_in_types", feature = "nalgebra"))]
+#[test]
+fn agrees_with_newtons_method() {
+    // The roots of T12 are cos((2k + 1)pi / 24)
+    let chebyshev = Family::<f64>::ChebyshevFirst.polynomial(12);
+    let roots = chebyshev.roots().unwrap();
+    for (k, root) in roots.iter().rev().enumerate() {
+        let expected = ((2 * k + 1) as f64 * core::f64::consts::PI / 24.).cos();
+        assert!((root.re - expected).abs() < 1e-10 && root.im.abs() < 1e-10);
+        assert!((newton(&chebyshev, root.re) - root.re).abs() < 1e-10);
+    }
+
+    // Roots spread over six orders of magnitude, which need balancing
+    let (a, b, c) = (1e-3, 1., 1e3);
+    let spread: Polynomial<f64> = Polynomial(vec![
+        terms!(1., 3.),
+        terms!(-(a + b + c), 2.),
+        terms!(a * b + b * c + a * c, 1.),
+        terms!(-a * b * c, 0.),
+    ]);
+    let roots = spread.roots().unwrap();
+    for (root, expected) in roots.iter().zip([a, b, c].iter()) {
+        let polished = newton(&spread, root.re);
+        assert!((root.re - polished).abs() < 1e-9 * expected);
+        assert!((polished - expected).abs() < 1e-12 * expected);
+    }
+}
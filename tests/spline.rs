@@ -0,0 +1,120 @@
+#[cfg(feature = "built_in_types")]
+use cakcukus::{
+    spline::Boundary,
+    traits::{Differentiation, Integration, TermTrait},
+    Spline,
+};
+
+#[cfg(feature = "built_in_types")]
+#[test]
+fn boundary_conditions() {
+    let xs = [0f64, 0.5, 1.5, 2., 3.];
+    let ys: Vec<f64> = xs.iter().map(|x| x.sin()).collect();
+
+    let natural = Spline::cubic(&xs, &ys, Boundary::Natural);
+    let second = natural.differentiate_self();
+    assert!(second.differentiate(&0.).abs() < 1e-12);
+    assert!(second.differentiate(&3.).abs() < 1e-12);
+
+    let clamped = Spline::cubic(&xs, &ys, Boundary::Clamped(1., 3f64.cos()));
+    assert!((clamped.differentiate(&0.) - 1.).abs() < 1e-12);
+    assert!((clamped.differentiate(&3.) - 3f64.cos()).abs() < 1e-12);
+
+    for spline in [natural, clamped].iter() {
+        for (x, y) in xs.iter().zip(ys.iter()) {
+            assert!((spline.sum_with_respect_to(x) - y).abs() < 1e-12);
+        }
+        // Continuous first and second derivatives at the knots
+        let second = spline.differentiate_self();
+        for knot in xs[1..4].iter() {
+            let (before, after) = (knot - 1e-9, knot + 1e-9);
+            assert!((spline.differentiate(&before) - spline.differentiate(&after)).abs() < 1e-6);
+            assert!((second.differentiate(&before) - second.differentiate(&after)).abs() < 1e-6);
+        }
+    }
+}
+
+#[cfg(feature = "built_in_types")]
+#[test]
+fn not_a_knot_reproduces_cubics() {
+    let cubic = |x: f64| 2. * x.powi(3) - x * x + 3.;
+    let xs = [-1., 0.2, 0.7, 1.1, 2., 2.5];
+    let ys: Vec<f64> = xs.iter().map(|x| cubic(*x)).collect();
+
+    let spline = Spline::cubic(&xs, &ys, Boundary::NotAKnot);
+    for i in 0..=40 {
+        let x = -1. + 0.0875 * i as f64;
+        assert!((spline.sum_with_respect_to(&x) - cubic(x)).abs() < 1e-10);
+    }
+
+    // Three points give the parabola through them
+    let parabola = Spline::cubic(&[0f64, 1., 3.], &[1., 2., 10.], Boundary::NotAKnot);
+    assert!((parabola.sum_with_respect_to(&2.) - 5.).abs() < 1e-12);
+}
+
+#[cfg(feature = "built_in_types")]
+#[test]
+fn integrates_across_pieces() {
+    let xs = [0f64, 1., 2., 4.];
+    let spline = Spline::cubic(&xs, &[0., 1., 4., 16.], Boundary::Clamped(0., 8.));
+
+    // Clamped to x^2's slopes, so the spline is x^2
+    assert!((spline.integrate(0., 4.) - 64. / 3.).abs() < 1e-10);
+    assert!((spline.integrate(0.5, 3.) - (27. - 0.125) / 3.).abs() < 1e-10);
+    assert!((spline.integrate(3., 0.5) + (27. - 0.125) / 3.).abs() < 1e-10);
+
+    let antiderivative = spline.integrate_self();
+    assert!(antiderivative.sum_with_respect_to(&0.).abs() < 1e-12);
+    assert!((antiderivative.sum_with_respect_to(&3.) - 9.).abs() < 1e-10);
+}
+
+#[cfg(feature = "built_in_types")]
+#[test]
+fn any_degree() {
+    let xs = [-1f64, 0.2, 0.7, 1.1, 2., 2.5, 3.2, 4.];
+    let ys: Vec<f64> = xs.iter().map(|x| x.sin()).collect();
+
+    // Degree 3 is the not-a-knot cubic
+    let cubic = Spline::cubic(&xs, &ys, Boundary::NotAKnot);
+    let general = Spline::interpolating(&xs, &ys, 3);
+    for i in 0..=50 {
+        let x = -1. + 0.1 * i as f64;
+        assert!((general.sum_with_respect_to(&x) - cubic.sum_with_respect_to(&x)).abs() < 1e-12);
+    }
+
+    // Each degree reproduces polynomials of that degree, for odd and even
+    for degree in 1..=6 {
+        let p = |x: f64| (0..=degree).fold(0., |total, k| total * x + (k as f64 - 2.));
+        let ys: Vec<f64> = xs.iter().map(|x| p(*x)).collect();
+        let spline = Spline::interpolating(&xs, &ys, degree);
+        for i in 0..=50 {
+            let x = -1. + 0.1 * i as f64;
+            assert!((spline.sum_with_respect_to(&x) - p(x)).abs() < 1e-8);
+        }
+    }
+
+    // A quartic spline has three continuous derivatives at its knots
+    let quartic = Spline::interpolating(&xs, &ys, 4);
+    for (x, y) in xs.iter().zip(ys.iter()) {
+        assert!((quartic.sum_with_respect_to(x) - y).abs() < 1e-12);
+    }
+    let mut derivative = quartic.clone();
+    for _ in 0..3 {
+        for knot in quartic.knots()[1..quartic.knots().len() - 1].iter() {
+            let (before, after) = (knot - 1e-9, knot + 1e-9);
+            assert!(
+                (derivative.sum_with_respect_to(&before) - derivative.sum_with_respect_to(&after))
+                    .abs()
+                    < 1e-6
+            );
+        }
+        derivative = derivative.differentiate_self();
+    }
+}
+
+#[cfg(feature = "built_in_types")]
+#[test]
+#[should_panic(expected = "A spline needs at least two knots")]
+fn single_knot() {
+    let _: Spline<f64> = Spline::new(vec![0.], Vec::new());
+}
@@ -0,0 +1,57 @@
+#[cfg(feature = "built_in_types")]
+use cakcukus::{
+    finite_difference::NumericalFunction,
+    taylor::{lagrange_bound, Taylor, TaylorSeries},
+    terms,
+    traits::TermTrait,
+    Polynomial,
+};
+
+#[cfg(feature = "built_in_types")]
+#[test]
+fn polynomials_expand_to_themselves() {
+    // x^3 - 2x + 1
+    let cubic: Polynomial<f64> = terms!(1., 3., -2., 1., 1., 0.);
+
+    let mut taylor = cubic.taylor(2., 3).polynomial;
+    taylor.simplify();
+
+    let mut expected = cubic.clone();
+    expected.simplify();
+    for (a, b) in taylor.0.iter().zip(expected.0.iter()) {
+        assert!((a.coefficient - b.coefficient).abs() < 1e-12);
+        assert_eq!(a.exponent, b.exponent);
+    }
+
+    // Truncating to a line gives the tangent at 2, being 10x - 15
+    let tangent = cubic.taylor(2., 1);
+    assert!((tangent.sum_with_respect_to(&0.) + 15.).abs() < 1e-12);
+    assert!((tangent.sum_with_respect_to(&1.) + 5.).abs() < 1e-12);
+}
+
+#[cfg(feature = "built_in_types")]
+#[test]
+fn remainder_bounds_hold() {
+    let sin = NumericalFunction::new(|x: f64| x.sin());
+
+    let taylor = sin.maclaurin(5);
+    for x in [0.1, 0.5, 1.].iter() {
+        let error = (taylor.sum_with_respect_to(x) - x.sin()).abs();
+        // Every derivative of sin is bounded by 1
+        assert!(error <= taylor.lagrange_bound(*x, 1.) + 1e-6);
+        assert!(error <= sin.taylor_remainder_bound(0., 5, *x) + 1e-6);
+    }
+
+    // M |x - c|^3 / 3!, from the free function
+    assert!((lagrange_bound(1f64, 2, 3., 0.75) - 1.).abs() < 1e-12);
+}
+
+#[cfg(feature = "built_in_types")]
+#[test]
+fn from_coefficients() {
+    // 1 + 2(x - 1) + 3(x - 1)^2 = 3x^2 - 4x + 2
+    let taylor = Taylor::from_coefficients(&[1., 2., 3.], 1.);
+
+    assert_eq!(taylor.polynomial, terms!(3., 2., -4., 1., 2., 0.));
+    assert_eq!(taylor.order, 2);
+}